@@ -0,0 +1,54 @@
+use aoc_core::{parse, random::Rng, ParseError, Solution};
+
+type Calorie = i32;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    type Input = Vec<Calorie>;
+    type Part1 = Calorie;
+    type Part2 = Calorie;
+
+    fn parse(lines: &str) -> Result<Self::Input, ParseError> {
+        // get the total carry of each elf
+        aoc_core::blocks(lines)
+            .map(|elf_calories| {
+                // get the calories for each elf
+                elf_calories
+                    .lines()
+                    // convert the strings to number
+                    .map(parse::number::<Calorie>)
+                    // add all the lines
+                    .sum::<Result<Calorie, _>>()
+            })
+            .collect()
+    }
+
+    fn part_1(carry: &Self::Input) -> Self::Part1 {
+        // return the maximum number
+        carry.iter().max().copied().unwrap_or_default()
+    }
+
+    fn part_2(carry: &Self::Input) -> Self::Part2 {
+        let mut carry = carry.clone();
+
+        carry.sort();
+        carry.reverse();
+        carry.iter().take(3).sum::<Calorie>()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let elves = (0..size.max(1))
+            .map(|_| {
+                (0..rng.range(1..15))
+                    .map(|_| format!("{}\n", rng.range(1000..60_000)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        Some(elves.join("\n"))
+    }
+}
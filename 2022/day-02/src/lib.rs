@@ -0,0 +1,154 @@
+use aoc_core::{parse, random::Rng, ParseError, Solution};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy)]
+enum Score {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+impl FromStr for Score {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "X" => Ok(Self::Rock),
+            "B" | "Y" => Ok(Self::Paper),
+            "C" | "Z" => Ok(Self::Scissors),
+            _ => Err(ParseError::new(s, "unknown move")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Outcome {
+    Lose = 0,
+    Draw = 3,
+    Win = 6,
+}
+
+impl FromStr for Outcome {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Self::Lose),
+            "Y" => Ok(Self::Draw),
+            "Z" => Ok(Self::Win),
+            _ => Err(ParseError::new(s, "unknown outcome")),
+        }
+    }
+}
+
+pub struct Round {
+    opponent: Score,
+    response: Score,
+    outcome: Outcome,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Round>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        data.lines()
+            .map(|line| {
+                let (opponent, response) = parse::split_once(line, " ")?;
+
+                // the second column is a move in part 1 and an outcome in part 2
+                Ok(Round {
+                    opponent: opponent.parse()?,
+                    response: response.parse()?,
+                    outcome: response.parse()?,
+                })
+            })
+            .collect()
+    }
+
+    fn part_1(rounds: &Self::Input) -> Self::Part1 {
+        rounds
+            .iter()
+            .map(|round| {
+                use Outcome::*;
+                use Score::*;
+                match (round.opponent, round.response) {
+                    (opponent, Rock) => {
+                        let outcome = match opponent {
+                            Paper => Lose,
+                            Rock => Draw,
+                            Scissors => Win,
+                        };
+
+                        outcome as i32 + Rock as i32
+                    }
+                    (opponent, Paper) => {
+                        let outcome = match opponent {
+                            Paper => Draw,
+                            Rock => Win,
+                            Scissors => Lose,
+                        };
+
+                        outcome as i32 + Paper as i32
+                    }
+                    (opponent, Scissors) => {
+                        let outcome = match opponent {
+                            Paper => Win,
+                            Rock => Lose,
+                            Scissors => Draw,
+                        };
+
+                        outcome as i32 + Scissors as i32
+                    }
+                }
+            })
+            .sum::<i32>()
+    }
+
+    fn part_2(rounds: &Self::Input) -> Self::Part2 {
+        rounds
+            .iter()
+            .map(|round| {
+                let elf_move = round.opponent;
+                let outcome = round.outcome;
+
+                use Outcome::*;
+                use Score::*;
+
+                let outcome_move = match outcome {
+                    Lose => match elf_move {
+                        Rock => Scissors,
+                        Scissors => Paper,
+                        Paper => Rock,
+                    },
+                    Draw => elf_move,
+                    Win => match elf_move {
+                        Rock => Paper,
+                        Scissors => Rock,
+                        Paper => Scissors,
+                    },
+                };
+
+                outcome_move as i32 + outcome as i32
+            })
+            .sum::<i32>()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let rounds = (0..size.max(1)).map(|_| {
+            format!(
+                "{} {}\n",
+                rng.pick(&["A", "B", "C"]),
+                rng.pick(&["X", "Y", "Z"])
+            )
+        });
+
+        Some(rounds.collect())
+    }
+}
@@ -0,0 +1,220 @@
+use aoc_core::{random::Rng, Alternative, ParseError, Solution};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone)]
+pub struct Rucksack {
+    all: Vec<char>,
+    compartments: (Vec<char>, Vec<char>),
+}
+
+impl Rucksack {
+    fn new(s: &str) -> Result<Self, ParseError> {
+        if let Some((i, c)) = s.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::new(
+                &s[i..i + c.len_utf8()],
+                "expected an item letter",
+            ));
+        }
+
+        let items = s.chars().collect::<Vec<_>>();
+
+        if items.len() % 2 != 0 {
+            return Err(ParseError::new(
+                s,
+                "expected two compartments of the same size",
+            ));
+        }
+
+        let size = items.len() / 2;
+
+        let compartment_one = items[0..size].to_vec();
+        let compartment_two = items[size..].to_vec();
+
+        let rucksack = Rucksack {
+            all: items,
+            compartments: (compartment_one, compartment_two),
+        };
+
+        if rucksack.shared_items().is_empty() {
+            return Err(ParseError::new(
+                s,
+                "expected an item type in both compartments",
+            ));
+        }
+
+        Ok(rucksack)
+    }
+
+    fn shared_items(&self) -> Vec<&char> {
+        let (compartment_one, compartment_two) = &self.compartments;
+
+        let compartment_one: HashSet<_> = compartment_one.iter().collect();
+        let compartment_two: HashSet<_> = compartment_two.iter().collect();
+
+        compartment_one
+            .intersection(&compartment_two)
+            .copied()
+            .collect()
+    }
+
+    fn to_priority_values(&self) -> Vec<i32> {
+        self.all.iter().map(map_to_value).collect()
+    }
+}
+
+fn map_to_value(c: &char) -> i32 {
+    match c {
+        'a'..='z' => *c as i32 - 96,
+        'A'..='Z' => *c as i32 - 38,
+        _ => 0,
+    }
+}
+
+fn to_priority_values(rucksack: &Rucksack) -> Vec<i32> {
+    rucksack
+        .shared_items()
+        .into_iter()
+        .map(map_to_value)
+        .collect()
+}
+
+fn get_prioritized_value(rucksack: &Rucksack) -> i32 {
+    *to_priority_values(rucksack).iter().max().unwrap()
+}
+
+/// The item types of `items` as one bit per priority.
+fn item_set(items: &[char]) -> u64 {
+    items.iter().fold(0, |set, c| set | 1 << map_to_value(c))
+}
+
+/// The highest priority in an item set, 0 for an empty one.
+fn highest_priority(set: u64) -> i32 {
+    match set {
+        0 => 0,
+        _ => 63 - set.leading_zeros() as i32,
+    }
+}
+
+/// A rucksack holding the `badge` and items of `pool`, with exactly one item
+/// type in both compartments.
+fn random_rucksack(rng: &mut Rng, badge: char, pool: &[char]) -> String {
+    let mut items = pool.to_vec();
+    items.push(badge);
+    rng.shuffle(&mut items);
+
+    // every other item only ever goes in one of the compartments
+    let (shared, rest) = items.split_first().unwrap();
+    let (left, right) = rest.split_at(rest.len() / 2);
+    let size = rng.range(2..16);
+
+    let mut compartment = |own: &[char]| {
+        let mut compartment = vec![*shared];
+
+        if own.contains(&badge) {
+            compartment.push(badge);
+        }
+
+        while compartment.len() < size {
+            compartment.push(*rng.pick(own));
+        }
+
+        rng.shuffle(&mut compartment);
+        compartment
+    };
+
+    compartment(left)
+        .into_iter()
+        .chain(compartment(right))
+        .collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    type Input = Vec<Rucksack>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        data.lines().map(Rucksack::new).collect()
+    }
+
+    fn part_1(rucksacks: &Self::Input) -> Self::Part1 {
+        rucksacks.iter().map(get_prioritized_value).sum::<i32>()
+    }
+
+    fn part_2(rucksacks: &Self::Input) -> Self::Part2 {
+        let rucksack_groups = rucksacks.chunks(3);
+
+        let group_values = rucksack_groups.map(|group| {
+            let values = group.iter().flat_map(|rucksack| {
+                let mut priority_vaules = rucksack.to_priority_values();
+
+                priority_vaules.sort();
+                priority_vaules.dedup();
+
+                priority_vaules
+            });
+
+            let in_all_sacks = values
+                .clone()
+                .filter(move |value| values.clone().filter(|v| v == value).count() == 3);
+
+            in_all_sacks.max().unwrap_or_default()
+        });
+
+        group_values.sum::<i32>()
+    }
+
+    fn alternatives() -> Vec<Alternative<Self::Input>> {
+        vec![
+            Alternative {
+                name: "bitset",
+                part: 1,
+                solve: |rucksacks| {
+                    let shared = rucksacks.iter().map(|rucksack| {
+                        let (one, two) = &rucksack.compartments;
+                        highest_priority(item_set(one) & item_set(two))
+                    });
+
+                    shared.sum::<i32>().to_string()
+                },
+            },
+            Alternative {
+                name: "bitset",
+                part: 2,
+                solve: |rucksacks| {
+                    // like the reference, a short last group has no badge
+                    let badges = rucksacks.chunks_exact(3).map(|group| {
+                        let sets = group.iter().map(|rucksack| item_set(&rucksack.all));
+                        highest_priority(sets.fold(u64::MAX, |shared, set| shared & set))
+                    });
+
+                    badges.sum::<i32>().to_string()
+                },
+            },
+        ]
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut data = String::new();
+
+        for _ in 0..size.div_ceil(3).max(1) {
+            let mut items = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+            rng.shuffle(&mut items);
+
+            // the badge is the only item type the three elves of a group share
+            let (&badge, pools) = items.split_first().unwrap();
+
+            for pool in pools.chunks(pools.len() / 3) {
+                data.push_str(&random_rucksack(rng, badge, pool));
+                data.push('\n');
+            }
+        }
+
+        Some(data)
+    }
+}
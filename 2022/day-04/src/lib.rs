@@ -0,0 +1,112 @@
+use aoc_core::{parse, random::Rng, ParseError, Solution};
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone)]
+pub struct Elf(RangeInclusive<i32>);
+
+impl Elf {
+    fn new(s: &str) -> Result<Self, ParseError> {
+        let (start, stop) = parse::split_once(s, "-")?;
+
+        Ok(Self(parse::number(start)?..=parse::number(stop)?))
+    }
+
+    fn fully_contains_another(&self, other: &Self) -> bool {
+        let s = &self.0;
+        let o = &other.0;
+
+        s.contains(o.start()) && s.contains(o.end()) || o.contains(s.start()) && o.contains(s.end())
+    }
+
+    fn partial_contains_another(&self, other: &Self) -> bool {
+        let s = &self.0;
+        let o = &other.0;
+
+        s.contains(o.start()) || s.contains(o.end()) || o.contains(s.start()) || o.contains(s.end())
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    type Input = Vec<(Elf, Elf)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        data.lines()
+            .map(|line| {
+                let (first, second) = parse::split_once(line, ",")?;
+
+                Ok((Elf::new(first)?, Elf::new(second)?))
+            })
+            .collect()
+    }
+
+    fn part_1(pairs: &Self::Input) -> Self::Part1 {
+        pairs
+            .iter()
+            .filter(|(first, second)| first.fully_contains_another(second))
+            .count()
+    }
+
+    fn part_2(pairs: &Self::Input) -> Self::Part2 {
+        pairs
+            .iter()
+            .filter(|(first, second)| first.partial_contains_another(second))
+            .count()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut sections = || {
+            let start = rng.range(1..100);
+            format!("{}-{}", start, rng.range(start..100))
+        };
+
+        Some(
+            (0..size.max(1))
+                .map(|_| format!("{},{}\n", sections(), sections()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn random(rng: &mut Rng) -> Elf {
+        let start = rng.range(1..20);
+        Elf(start..=rng.range(start..20))
+    }
+
+    #[test]
+    fn test_containment() {
+        for seed in 0..1000 {
+            let mut rng = Rng::new(seed);
+            let (a, b) = (random(&mut rng), random(&mut rng));
+            let overlap = a.0.clone().any(|section| b.0.contains(&section));
+
+            if a.fully_contains_another(&b) {
+                assert!(a.partial_contains_another(&b), "seed {}", seed);
+            }
+
+            assert_eq!(
+                a.fully_contains_another(&b),
+                b.fully_contains_another(&a),
+                "seed {}",
+                seed
+            );
+            assert_eq!(
+                a.partial_contains_another(&b),
+                b.partial_contains_another(&a),
+                "seed {}",
+                seed
+            );
+            assert_eq!(a.partial_contains_another(&b), overlap, "seed {}", seed);
+        }
+    }
+}
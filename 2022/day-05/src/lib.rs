@@ -0,0 +1,257 @@
+#![allow(unused_must_use)]
+use aoc_core::{
+    input::Normalize,
+    parse,
+    point::Point,
+    random::Rng,
+    visual::{Canvas, Frame},
+    ParseError, Solution,
+};
+use std::collections::VecDeque;
+
+type Stack = VecDeque<char>;
+type Step = (usize, usize, usize);
+
+pub struct Procedure {
+    stacks: Vec<Stack>,
+    steps: Vec<Step>,
+}
+
+fn create_stacks(s: &str) -> Result<Vec<Stack>, ParseError> {
+    let mut lines: Vec<&str> = s.lines().collect();
+    let numbers = lines
+        .pop()
+        .ok_or_else(|| ParseError::new(s, "expected a stack diagram"))?;
+    let nbr_of_stacks = numbers.split("   ").count();
+    let mut stacks: Vec<Stack> = vec![Stack::new(); nbr_of_stacks];
+
+    for line in lines {
+        let chars = line.char_indices().skip(1).step_by(4);
+
+        for (i, (offset, c)) in chars.enumerate() {
+            if !c.is_alphabetic() {
+                continue;
+            }
+
+            match stacks.get_mut(i) {
+                Some(stack) => stack.push_back(c),
+                None => {
+                    let text = &line[offset..offset + c.len_utf8()];
+                    return Err(ParseError::new(
+                        text,
+                        "crate outside of the numbered stacks",
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+/// Parses a step, keeping track of the `heights` of the stacks so that a step
+/// never takes more crates than there are.
+fn parse_step(step: &str, heights: &mut [usize]) -> Result<Step, ParseError> {
+    let [nbr_of_moves, from, to] = parse::pattern(step, "move {count} from {from} to {to}")?;
+
+    let stack = |s: &str| {
+        let stack = parse::number::<usize>(s)?;
+
+        match stack {
+            1.. if stack <= heights.len() => Ok(stack),
+            _ => Err(ParseError::new(s, "no such stack")),
+        }
+    };
+
+    let step = (parse::number(nbr_of_moves)?, stack(from)?, stack(to)?);
+    let (count, from, to) = step;
+
+    if count > heights[from - 1] {
+        return Err(ParseError::new(
+            nbr_of_moves,
+            format!("stack {} only holds {} crate(s)", from, heights[from - 1]),
+        ));
+    }
+
+    heights[from - 1] -= count;
+    heights[to - 1] += count;
+
+    Ok(step)
+}
+
+/// Runs the crane of `part` over every step: the CrateMover 9000 moves the
+/// crates one at a time, the 9001 all at once. `observe` sees the stacks after every step.
+fn rearrange(
+    procedure: &Procedure,
+    part: u8,
+    mut observe: impl FnMut(&Step, &[Stack]),
+) -> Vec<Stack> {
+    let mut stacks = procedure.stacks.clone();
+
+    for step @ &(nbr_of_moves, from, to) in &procedure.steps {
+        if part == 1 {
+            for _ in 0..nbr_of_moves {
+                let to_be_moved = stacks[from - 1].pop_front().unwrap();
+                stacks[to - 1].push_front(to_be_moved);
+            }
+        } else {
+            let to_be_moved = stacks[from - 1].drain(0..nbr_of_moves).collect::<Vec<_>>();
+
+            stacks[to - 1] = to_be_moved
+                .into_iter()
+                .chain(stacks[to - 1].drain(..))
+                .collect();
+        }
+
+        observe(step, &stacks);
+    }
+
+    stacks
+}
+
+/// Draws the stacks like the diagram of the input.
+fn draw_stacks(stacks: &[Stack]) -> Canvas {
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0) + 1;
+    let mut canvas = Canvas::new(4 * stacks.len(), height);
+
+    for (i, stack) in stacks.iter().enumerate() {
+        let x = 4 * i as i64;
+        let bottom = height as i64 - 1;
+
+        for (depth, c) in stack.iter().enumerate() {
+            let y = bottom - (stack.len() - depth) as i64;
+            canvas.text(Point::new(x, y), &format!("[{}]", c));
+        }
+
+        canvas.text(Point::new(x + 1, bottom), &(i + 1).to_string());
+    }
+
+    canvas
+}
+
+fn top_crates(stacks: Vec<Stack>) -> String {
+    stacks.into_iter().fold(String::new(), |mut acc, vec| {
+        acc.push(vec[0]);
+        acc
+    })
+}
+
+/// Draws the stack diagram of the input, every line padded to the full width.
+fn stack_diagram(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    let mut diagram = String::new();
+
+    for level in (0..height).rev() {
+        let cells = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        diagram.push_str(&cells.join(" "));
+        diagram.push('\n');
+    }
+
+    let numbers = (1..=stacks.len())
+        .map(|i| format!(" {} ", i))
+        .collect::<Vec<_>>();
+
+    diagram.push_str(&numbers.join(" "));
+    diagram.push('\n');
+    diagram
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+    // the stack diagram is padded with spaces to line the crates up
+    const NORMALIZE: Normalize = Normalize::LineEndings;
+
+    type Input = Procedure;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let (diagram, steps) = parse::split_once(data, "\n\n")?;
+        let stacks = create_stacks(diagram)?;
+        let mut heights = stacks.iter().map(Stack::len).collect::<Vec<_>>();
+
+        let steps = steps
+            .lines()
+            .map(|step| parse_step(step, &mut heights))
+            .collect::<Result<_, _>>()?;
+
+        // the answer is made of the crate on top of every stack
+        if let Some(empty) = heights.iter().position(|&height| height == 0) {
+            let last = data.lines().rfind(|line| !line.is_empty()).unwrap_or(data);
+
+            return Err(ParseError::new(
+                last,
+                format!("stack {} is empty at the end", empty + 1),
+            ));
+        }
+
+        Ok(Procedure { stacks, steps })
+    }
+
+    fn part_1(procedure: &Self::Input) -> Self::Part1 {
+        top_crates(rearrange(procedure, 1, |_, _| {}))
+    }
+
+    fn part_2(procedure: &Self::Input) -> Self::Part2 {
+        top_crates(rearrange(procedure, 2, |_, _| {}))
+    }
+
+    fn visualize(procedure: &Self::Input, part: u8, show: &mut dyn FnMut(Frame)) -> bool {
+        show(Frame::new(
+            "Starting stacks",
+            draw_stacks(&procedure.stacks),
+        ));
+
+        rearrange(procedure, part, |&(nbr_of_moves, from, to), stacks| {
+            let caption = format!("move {} from {} to {}", nbr_of_moves, from, to);
+            show(Frame::new(caption, draw_stacks(stacks)));
+        });
+
+        true
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let letters = ('A'..='Z').collect::<Vec<_>>();
+        let mut stacks = (0..rng.range(3..10))
+            .map(|_| {
+                (0..rng.range(1..8))
+                    .map(|_| *rng.pick(&letters))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // with more crates than stacks there always is one to take from
+        stacks[0].push(*rng.pick(&letters));
+
+        let mut data = stack_diagram(&stacks);
+        data.push('\n');
+
+        for _ in 0..size.max(1) {
+            let sources = (0..stacks.len())
+                .filter(|&i| stacks[i].len() > 1)
+                .collect::<Vec<_>>();
+            let from = *rng.pick(&sources);
+            let to = (from + rng.range(1..stacks.len())) % stacks.len();
+            // a stack is never emptied, so there always is a crate on top at the end
+            let count = rng.range(1..stacks[from].len());
+
+            let left = stacks[from].len() - count;
+            let moved = stacks[from].split_off(left);
+            stacks[to].extend(moved);
+
+            data.push_str(&format!("move {} from {} to {}\n", count, from + 1, to + 1));
+        }
+
+        Some(data)
+    }
+}
@@ -0,0 +1,106 @@
+use aoc_core::{random::Rng, Alternative, ParseError, Solution};
+use std::{collections::HashMap, str::Chars};
+
+fn find_marker(chars: Chars, n: usize) -> usize {
+    let mut count = 0;
+    let offset = n - 1;
+
+    for (i, c) in chars.clone().enumerate() {
+        count += 1;
+
+        if i < offset {
+            continue;
+        }
+
+        let mut prev = chars
+            .clone()
+            .skip(i - offset)
+            .take(offset)
+            .collect::<Vec<_>>();
+
+        prev.push(c);
+        prev.sort();
+        prev.dedup();
+
+        if prev.len() == n {
+            break;
+        }
+    }
+
+    count
+}
+
+/// Same as [`find_marker`] in a single pass, keeping the window free of
+/// duplicates by moving its start past the last time the new character was seen.
+fn find_marker_linear(datastream: &str, n: usize) -> usize {
+    let mut last_seen = HashMap::new();
+    let mut start = 0;
+    let mut count = 0;
+
+    for (i, c) in datastream.chars().enumerate() {
+        count += 1;
+
+        if let Some(previous) = last_seen.insert(c, i) {
+            start = start.max(previous + 1);
+        }
+
+        if i + 1 - start == n {
+            break;
+        }
+    }
+
+    count
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Ok(data.trim().to_string())
+    }
+
+    fn part_1(datastream: &Self::Input) -> Self::Part1 {
+        find_marker(datastream.chars(), 4)
+    }
+
+    fn part_2(datastream: &Self::Input) -> Self::Part2 {
+        find_marker(datastream.chars(), 14)
+    }
+
+    fn alternatives() -> Vec<Alternative<Self::Input>> {
+        vec![
+            Alternative {
+                name: "linear",
+                part: 1,
+                solve: |datastream| find_marker_linear(datastream, 4).to_string(),
+            },
+            Alternative {
+                name: "linear",
+                part: 2,
+                solve: |datastream| find_marker_linear(datastream, 14).to_string(),
+            },
+        ]
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // too few letters for a start-of-message marker to show up by chance
+        let letters = ('a'..='m').collect::<Vec<_>>();
+        let length = size.max(14);
+        let mut datastream = (0..length).map(|_| *rng.pick(&letters)).collect::<Vec<_>>();
+
+        let mut marker = ('a'..='z').collect::<Vec<_>>();
+        rng.shuffle(&mut marker);
+
+        let start = rng.range(0..length - 13);
+        datastream[start..start + 14].copy_from_slice(&marker[..14]);
+
+        Some(datastream.into_iter().chain(['\n']).collect())
+    }
+}
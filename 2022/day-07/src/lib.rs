@@ -0,0 +1,203 @@
+use aoc_core::{parse, random::Rng, ParseError, Solution};
+use std::collections::HashMap;
+use std::str::Lines;
+
+fn parse_commands(lines: Lines) -> Result<HashMap<String, u32>, ParseError> {
+    let mut context: Vec<&str> = vec![];
+    let mut directories: HashMap<String, u32> = HashMap::new();
+
+    for line in lines {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+
+        match words.as_slice() {
+            ["$", "cd", dir_name] => match *dir_name {
+                "/" => {
+                    context.push("");
+                }
+                ".." => {
+                    context.pop();
+                }
+                _ => {
+                    context.push(dir_name);
+                }
+            },
+            ["$", "ls"] => {}
+            ["$", ..] => return Err(ParseError::new(line, "unknown command")),
+            ["dir", _] => {}
+            [size, _] => {
+                // this is a file
+                let size = parse::number::<u32>(size)?;
+
+                for i in 1..context.len() + 1 {
+                    let path = context[0..i].join("/");
+                    let files = directories.entry(path).or_insert(0);
+                    *files = files
+                        .checked_add(size)
+                        .ok_or_else(|| ParseError::new(line, "the directory is too large"))?;
+                }
+            }
+            _ => return Err(ParseError::new(line, "expected a command or a listing")),
+        }
+    }
+
+    Ok(directories)
+}
+
+#[derive(Default)]
+struct Directory {
+    name: String,
+    directories: Vec<usize>,
+    files: Vec<(String, u32)>,
+}
+
+fn random_name(rng: &mut Rng) -> String {
+    (0..rng.range(1..9))
+        .map(|_| (b'a' + rng.range(0..26)) as char)
+        .collect()
+}
+
+/// Lists the directory at `id` and then walks into every directory below it.
+fn write_transcript(tree: &[Directory], id: usize, transcript: &mut String) {
+    let directory = &tree[id];
+    transcript.push_str("$ ls\n");
+
+    for &child in &directory.directories {
+        transcript.push_str(&format!("dir {}\n", tree[child].name));
+    }
+
+    for (name, size) in &directory.files {
+        transcript.push_str(&format!("{} {}\n", size, name));
+    }
+
+    for &child in &directory.directories {
+        transcript.push_str(&format!("$ cd {}\n", tree[child].name));
+        write_transcript(tree, child, transcript);
+        transcript.push_str("$ cd ..\n");
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+
+    type Input = HashMap<String, u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_commands(data.lines())
+    }
+
+    fn part_1(directories: &Self::Input) -> Self::Part1 {
+        directories.values().fold(0, |mut acc, &value| {
+            acc += if value <= 100000 { value } else { 0 };
+            acc
+        })
+    }
+
+    fn part_2(directories: &Self::Input) -> Self::Part2 {
+        let total_space = 70_000_000;
+        let needed_space = 30_000_000;
+        let max_usage = total_space - needed_space;
+
+        // files listed before `cd /` are never counted, so there may be no root
+        let root_size = directories.get("").copied().unwrap_or_default();
+
+        // with enough free space already, nothing has to be deleted
+        let need_to_free = match root_size.checked_sub(max_usage) {
+            Some(need_to_free) if need_to_free > 0 => need_to_free,
+            _ => return 0,
+        };
+
+        directories
+            .values()
+            .copied()
+            .filter(|value| value >= &need_to_free)
+            .min()
+            .unwrap_or(root_size)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let files = size.max(1);
+        let mut tree = vec![Directory::default()];
+
+        for id in 1..files / 3 + 1 {
+            let parent = rng.range(0..id);
+            let name = random_name(rng);
+
+            if tree[parent]
+                .directories
+                .iter()
+                .any(|&i| tree[i].name == name)
+            {
+                continue;
+            }
+
+            let child = tree.len();
+            tree[parent].directories.push(child);
+            tree.push(Directory {
+                name,
+                ..Directory::default()
+            });
+        }
+
+        let mut add_file = |rng: &mut Rng, size: u32| {
+            let id = rng.range(0..tree.len());
+            let name = match rng.chance(0.5) {
+                true => format!("{}.{}", random_name(rng), random_name(rng)),
+                false => random_name(rng),
+            };
+
+            tree[id].files.push((name, size));
+            size
+        };
+
+        // the disk has to be too full for the update, as in the real inputs
+        let largest = (60_000_000 / files as u32).clamp(2, 300_000);
+        let mut used = (0..files)
+            .map(|_| {
+                let size = rng.range(1..largest);
+                add_file(rng, size)
+            })
+            .sum::<u32>();
+
+        while used <= 40_000_000 {
+            let size = rng.range(1_000_000..8_000_000);
+            used += add_file(rng, size);
+        }
+
+        let mut transcript = "$ cd /\n".to_string();
+        write_transcript(&tree, 0, &mut transcript);
+
+        Some(transcript)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directory_sizes() {
+        for seed in 0..50 {
+            let data = Day07::generate(&mut Rng::new(seed), 100).unwrap();
+            let directories = Day07::parse(&data).unwrap();
+            let files = data
+                .lines()
+                .filter_map(|line| line.split_once(' '))
+                .filter_map(|(size, _)| size.parse::<u32>().ok())
+                .sum::<u32>();
+
+            assert_eq!(directories[""], files, "seed {}", seed);
+
+            // a directory holds at least what is in any directory below it
+            for (path, &size) in &directories {
+                if let Some((parent, _)) = path.rsplit_once('/') {
+                    assert!(directories[parent] >= size, "seed {}", seed);
+                }
+            }
+        }
+    }
+}
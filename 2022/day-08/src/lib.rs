@@ -0,0 +1,113 @@
+use aoc_core::{
+    grid::Grid,
+    image::{Colormap, Image, Rgb},
+    point::{Direction, Point},
+    random::Rng,
+    ParseError, Solution,
+};
+
+type Forest = Grid<u32>;
+
+fn parse_forest(data: &str) -> Result<Forest, ParseError> {
+    Grid::parse(data, |s| {
+        s.chars()
+            .next()
+            .and_then(|c| c.to_digit(10))
+            .ok_or_else(|| ParseError::new(s, "expected a tree height"))
+    })
+}
+
+fn is_visible(forest: &Forest, tree: Point) -> bool {
+    Direction::ALL.into_iter().any(|direction| {
+        forest
+            .ray(tree, direction.vector())
+            .all(|other| forest[other] < forest[tree])
+    })
+}
+
+fn scenic_score(forest: &Forest, tree: Point) -> usize {
+    Direction::ALL
+        .into_iter()
+        .map(|direction| {
+            let mut val = 0;
+
+            for other in forest.ray(tree, direction.vector()) {
+                val += 1;
+
+                if forest[other] >= forest[tree] {
+                    break;
+                }
+            }
+
+            val
+        })
+        .product()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+
+    type Input = Forest;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_forest(data)
+    }
+
+    fn part_1(forest: &Self::Input) -> Self::Part1 {
+        forest
+            .points()
+            .filter(|&tree| is_visible(forest, tree))
+            .count()
+    }
+
+    fn part_2(forest: &Self::Input) -> Self::Part2 {
+        forest
+            .points()
+            .map(|tree| scenic_score(forest, tree))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn images(forest: &Self::Input) -> Vec<(&'static str, Image)> {
+        let mut visibility = Image::new(forest.columns(), forest.rows(), Rgb::BLACK);
+        let mut scenic = visibility.clone();
+
+        let scores = forest
+            .points()
+            .map(|tree| (tree, scenic_score(forest, tree)))
+            .collect::<Vec<_>>();
+        let best = scores.iter().map(|&(_, score)| score).max().unwrap_or(0);
+
+        for (tree, score) in scores {
+            // visible trees are green, hidden ones gray, both lighter when taller
+            let shade = Colormap::GRAYSCALE.scale(forest[tree] as f64, -4.0, 9.0);
+            let color = match is_visible(forest, tree) {
+                true => Rgb(0, shade.1, 0),
+                false => shade,
+            };
+
+            visibility.set(tree, color);
+
+            // the best scores are rare, a square root keeps the others visible
+            let t = (score as f64 / best.max(1) as f64).sqrt();
+            scenic.set(tree, Colormap::HEAT.at(t));
+        }
+
+        vec![("visibility", visibility), ("scenic", scenic)]
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let side = size.max(1);
+        let rows = (0..side).map(|_| {
+            let heights = (0..side).map(|_| rng.range(0..10).to_string());
+            heights.collect::<String>() + "\n"
+        });
+
+        Some(rows.collect())
+    }
+}
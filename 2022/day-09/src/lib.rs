@@ -0,0 +1,168 @@
+use aoc_core::{
+    image::{Image, Rgb},
+    parse,
+    point::{Direction, Point},
+    random::Rng,
+    visual::{Canvas, Frame},
+    ParseError, Solution,
+};
+use std::collections::HashSet;
+
+pub struct Move {
+    direction: Direction,
+    distance: i32,
+}
+
+impl Move {
+    fn new(s: &str) -> Result<Self, ParseError> {
+        let (direction, distance) = parse::split_once(s, " ")?;
+
+        Ok(Self {
+            direction: direction.parse()?,
+            distance: parse::number(distance)?,
+        })
+    }
+}
+
+/// Moves the head of a rope of `rope_size` knots, one step at a time, returning
+/// the positions the tail visited. `observe` sees the rope after every step.
+fn rope_movements(
+    rope_size: usize,
+    moves: &[Move],
+    mut observe: impl FnMut(&Move, &[Point], &HashSet<Point>),
+) -> HashSet<Point> {
+    let mut rope = vec![Point::ORIGIN; rope_size];
+    let mut visited = HashSet::from([Point::ORIGIN]);
+
+    moves.iter().for_each(|m| {
+        for _ in 0..m.distance {
+            rope[0] += m.direction.vector();
+
+            for i in 0..rope_size - 1 {
+                let head = rope[i];
+                let tail = &mut rope[i + 1];
+
+                // the tail follows as soon as it no longer touches the head,
+                // diagonally when they are not in the same row or column
+                if head.chebyshev(*tail) > 1 {
+                    *tail += (head - *tail).signum();
+                }
+            }
+
+            visited.insert(*rope.last().unwrap());
+            observe(m, &rope, &visited);
+        }
+    });
+
+    visited
+}
+
+/// Draws the part of the plane around the head, like the examples of the puzzle.
+fn draw_rope(rope: &[Point], visited: &HashSet<Point>) -> Canvas {
+    let (width, height) = (41, 21);
+    let origin = rope[0] - Point::new(width / 2, height / 2);
+    let mut canvas = Canvas::new(width as usize, height as usize);
+
+    for &point in visited {
+        canvas.draw(point - origin, '#');
+    }
+
+    canvas.draw(Point::ORIGIN - origin, 's');
+
+    for (i, &knot) in rope.iter().enumerate().rev() {
+        let c = match i {
+            0 => 'H',
+            _ if rope.len() == 2 => 'T',
+            _ => char::from_digit(i as u32, 36).unwrap_or('+'),
+        };
+
+        canvas.draw(knot - origin, c);
+    }
+
+    canvas
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+
+    type Input = Vec<Move>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        data.lines().map(Move::new).collect()
+    }
+
+    fn part_1(moves: &Self::Input) -> Self::Part1 {
+        rope_movements(2, moves, |_, _, _| {}).len()
+    }
+
+    fn part_2(moves: &Self::Input) -> Self::Part2 {
+        rope_movements(10, moves, |_, _, _| {}).len()
+    }
+
+    fn visualize(moves: &Self::Input, part: u8, show: &mut dyn FnMut(Frame)) -> bool {
+        let rope_size = if part == 1 { 2 } else { 10 };
+
+        rope_movements(rope_size, moves, |m, rope, visited| {
+            let caption = format!(
+                "{:?} {}, head at {}, {} visited",
+                m.direction,
+                m.distance,
+                rope[0],
+                visited.len()
+            );
+
+            show(Frame::new(caption, draw_rope(rope, visited)));
+        });
+
+        true
+    }
+
+    fn images(moves: &Self::Input) -> Vec<(&'static str, Image)> {
+        let visited = |rope_size| {
+            let points = rope_movements(rope_size, moves, |_, _, _| {});
+            let points = points.into_iter().collect::<Vec<_>>();
+
+            Image::from_points(&points, Rgb::WHITE, Rgb::BLACK)
+        };
+
+        vec![
+            ("visited-part1", visited(2)),
+            ("visited-part2", visited(10)),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let moves = (0..size.max(1))
+            .map(|_| format!("{} {}\n", rng.pick(&["U", "R", "D", "L"]), rng.range(1..20)));
+
+        Some(moves.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_knots_stay_touching() {
+        for seed in 0..50 {
+            let data = Day09::generate(&mut Rng::new(seed), 50).unwrap();
+            let moves = Day09::parse(&data).unwrap();
+
+            for rope_size in [2, 10] {
+                rope_movements(rope_size, &moves, |_, rope, visited| {
+                    for pair in rope.windows(2) {
+                        assert!(pair[0].chebyshev(pair[1]) <= 1, "seed {}", seed);
+                    }
+
+                    assert!(visited.contains(rope.last().unwrap()), "seed {}", seed);
+                });
+            }
+        }
+    }
+}
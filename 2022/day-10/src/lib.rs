@@ -0,0 +1,208 @@
+use aoc_core::{
+    grid::Grid,
+    image::{Image, Rgb},
+    parse,
+    point::Point,
+    random::Rng,
+    visual::{Canvas, Frame},
+    ParseError, Solution,
+};
+
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+
+#[derive(Debug)]
+pub struct Instruction {
+    operation: Operation,
+    cycles: usize,
+}
+
+#[derive(Debug)]
+enum Operation {
+    Noop,
+    Addx(i32),
+}
+
+fn parse_instructions(data: &str) -> Result<Vec<Instruction>, ParseError> {
+    let lines = data.lines();
+    let mut instructions = Vec::new();
+
+    for line in lines {
+        let instruction = if line == "noop" {
+            Instruction {
+                operation: Operation::Noop,
+                cycles: 1,
+            }
+        } else {
+            let [value] = parse::pattern(line, "addx {value}")
+                .map_err(|_| ParseError::new(line, "expected `noop` or `addx {value}`"))?;
+
+            Instruction {
+                operation: Operation::Addx(parse::number(value)?),
+                cycles: 2,
+            }
+        };
+
+        instructions.push(instruction);
+    }
+
+    let cycles = instructions.iter().map(|i| i.cycles).sum::<usize>();
+
+    if cycles < CRT_WIDTH * CRT_HEIGHT {
+        let last = data.lines().last().unwrap_or(data);
+
+        return Err(ParseError::new(
+            last,
+            format!(
+                "the program ends after {} cycles, the screen takes {}",
+                cycles,
+                CRT_WIDTH * CRT_HEIGHT
+            ),
+        ));
+    }
+
+    Ok(instructions)
+}
+
+fn cycle(instructions: &[Instruction], padd: bool) -> Vec<i32> {
+    let mut register = 1;
+    let mut cycles = vec![];
+
+    if padd {
+        cycles.push(register);
+    }
+
+    instructions.iter().for_each(|instruction| {
+        for i in 0..instruction.cycles {
+            match instruction.operation {
+                Operation::Noop => cycles.push(register),
+                Operation::Addx(value) => {
+                    cycles.push(register);
+
+                    if i == instruction.cycles - 1 {
+                        register += value;
+                    }
+                }
+            }
+        }
+    });
+
+    cycles
+}
+
+/// The pixel drawn at `position` while the sprite is centered on `register`.
+fn pixel(register: i32, position: usize) -> char {
+    if (register - (position % CRT_WIDTH) as i32).abs() <= 1 {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+/// Draws the CRT once the first `drawn` pixels have been scanned, with the
+/// sprite of the current cycle below it.
+fn draw_crt(cycles: &[i32], drawn: usize) -> Canvas {
+    let mut canvas = Canvas::new(CRT_WIDTH, CRT_HEIGHT + 2);
+
+    for (i, &register) in cycles.iter().enumerate().take(drawn) {
+        let point = Point::new((i % CRT_WIDTH) as i64, (i / CRT_WIDTH) as i64);
+        canvas.draw(point, pixel(register, i));
+    }
+
+    let register = cycles[drawn - 1] as i64;
+
+    for x in register - 1..=register + 1 {
+        canvas.draw(Point::new(x, CRT_HEIGHT as i64 + 1), '=');
+    }
+
+    canvas
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(data)
+    }
+
+    fn part_1(instructions: &Self::Input) -> Self::Part1 {
+        let cycles = cycle(instructions, true);
+
+        (20..=220).step_by(40).map(|i| cycles[i] * i as i32).sum()
+    }
+
+    fn part_2(instructions: &Self::Input) -> Self::Part2 {
+        let cycles = cycle(instructions, false);
+
+        let mut str = String::new();
+
+        for (i, row) in cycles.chunks_exact(CRT_WIDTH).enumerate() {
+            if i != 0 {
+                str.push('\n');
+            }
+
+            for (j, &register) in row.iter().enumerate() {
+                str.push(pixel(register, j));
+            }
+        }
+
+        str
+    }
+
+    fn visualize(instructions: &Self::Input, part: u8, show: &mut dyn FnMut(Frame)) -> bool {
+        // only the second part draws on the screen
+        if part != 2 {
+            return false;
+        }
+
+        let cycles = cycle(instructions, false);
+
+        for drawn in 1..=cycles.len().min(CRT_WIDTH * CRT_HEIGHT) {
+            let caption = format!("cycle {}, X = {}", drawn, cycles[drawn - 1]);
+            show(Frame::new(caption, draw_crt(&cycles, drawn)));
+        }
+
+        true
+    }
+
+    fn images(instructions: &Self::Input) -> Vec<(&'static str, Image)> {
+        let screen = Self::part_2(instructions);
+        let lit = Grid::parse(&screen, |pixel| Ok(pixel == "#")).unwrap();
+        let crt = Image::from_grid(&lit, |&lit| match lit {
+            true => Rgb(255, 200, 80),
+            false => Rgb(30, 20, 10),
+        });
+
+        vec![("crt", crt)]
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // the program has to fill whole rows of the CRT, and at least all of it
+        let total = (size * 3 / 2).div_ceil(CRT_WIDTH).max(CRT_HEIGHT) * CRT_WIDTH;
+        let mut cycles = 0;
+        let mut register = 1;
+        let mut program = String::new();
+
+        while cycles < total {
+            if total - cycles > 1 && rng.chance(0.6) {
+                // the sprite wanders around but stays on the screen
+                let next = (register + rng.range(-8..9)).clamp(0, CRT_WIDTH as i32 - 1);
+                program.push_str(&format!("addx {}\n", next - register));
+                register = next;
+                cycles += 2;
+            } else {
+                program.push_str("noop\n");
+                cycles += 1;
+            }
+        }
+
+        Some(program)
+    }
+}
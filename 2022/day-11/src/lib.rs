@@ -0,0 +1,238 @@
+use aoc_core::{parse, random::Rng, ParseError, Solution};
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    operation: Operation,
+    test: Test,
+    touches: u64,
+}
+
+impl Monkey {
+    /// Parses monkey number `id` out of a troop of `count` monkeys.
+    fn from_chunk(chunk: &str, id: usize, count: usize) -> Result<Self, ParseError> {
+        let mut lines = chunk.lines().map(str::trim);
+        let mut field = |template| {
+            let line = lines
+                .next()
+                .ok_or_else(|| ParseError::new(chunk, "incomplete monkey"))?;
+            let [value] = parse::pattern(line, template)?;
+
+            Ok::<_, ParseError>(value)
+        };
+
+        field("Monkey {id}:")?;
+
+        let items = field("Starting items: {items}")?
+            .split(", ")
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+        let operation = Operation::new(field("Operation: new = {expression}")?)?;
+        let divisor = field("Test: divisible by {divisor}")?;
+        let divisible = parse::number(divisor)?;
+
+        if divisible == 0 {
+            return Err(ParseError::new(divisor, "cannot test divisibility by 0"));
+        }
+
+        let target = |s: &str| match parse::number::<u64>(s)? {
+            target if target as usize == id => {
+                Err(ParseError::new(s, "a monkey cannot throw to itself"))
+            }
+            target if target as usize >= count => Err(ParseError::new(
+                s,
+                format!("expected a monkey below {}", count),
+            )),
+            target => Ok(target),
+        };
+        let if_true = target(field("If true: throw to monkey {id}")?)?;
+        let if_false = target(field("If false: throw to monkey {id}")?)?;
+
+        Ok(Self {
+            items,
+            operation,
+            touches: 0,
+            test: Test {
+                condition: divisible,
+                if_true,
+                if_false,
+            },
+        })
+    }
+
+    fn inspect(&mut self, old: u64, common_denominator: u64) -> u64 {
+        self.touches += 1;
+        self.operation.inspect(old, common_denominator)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Test {
+    condition: u64,
+    if_true: u64,
+    if_false: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Operation {
+    value_one: Value,
+    value_two: Value,
+    operation: OperationType,
+}
+
+impl Operation {
+    fn new(s: &str) -> Result<Self, ParseError> {
+        let [value_one, operation, value_two] = parse::pattern(s, "{value} {operator} {value}")?;
+
+        let get_value = |s: &str| {
+            if s == "old" {
+                Ok(Value::This)
+            } else {
+                Ok(Value::Other(parse::number(s)?))
+            }
+        };
+
+        let operation = match operation {
+            "+" => OperationType::Add,
+            "*" => OperationType::Multiply,
+            _ => return Err(ParseError::new(operation, "unknown operation")),
+        };
+
+        Ok(Self {
+            value_one: get_value(value_one)?,
+            value_two: get_value(value_two)?,
+            operation,
+        })
+    }
+
+    fn inspect(&self, old: u64, common_denominator: u64) -> u64 {
+        let value_one = match self.value_one {
+            Value::This => old,
+            Value::Other(v) => v,
+        };
+
+        let value_two = match self.value_two {
+            Value::This => old,
+            Value::Other(v) => v,
+        };
+
+        let result = match self.operation {
+            OperationType::Add => value_one + value_two,
+            OperationType::Multiply => value_one * value_two,
+        };
+
+        result % common_denominator
+    }
+}
+
+#[derive(Debug, Clone)]
+enum OperationType {
+    Add,
+    Multiply,
+}
+
+#[derive(Debug, Clone)]
+enum Value {
+    This,
+    Other(u64),
+}
+
+fn process(monkeys: &mut [Monkey], rounds: u64, divisor: u64) -> Vec<u64> {
+    let common_denoniator = monkeys.iter().map(|m| m.test.condition).product();
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            while let Some(item) = monkeys[i].items.pop_front() {
+                let new_item = monkeys[i].inspect(item, common_denoniator);
+                let new_item = new_item / divisor;
+
+                let next_monkey = if new_item.is_multiple_of(monkeys[i].test.condition) {
+                    monkeys[i].test.if_true
+                } else {
+                    monkeys[i].test.if_false
+                };
+
+                monkeys[next_monkey as usize].items.push_back(new_item);
+            }
+        }
+    }
+
+    let mut touches = monkeys.iter().map(|m| m.touches).collect::<Vec<_>>();
+    touches.sort();
+
+    touches
+}
+
+fn monkey_business(monkeys: &[Monkey], rounds: u64, divisor: u64) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    let touches = process(&mut monkeys, rounds, divisor);
+
+    touches.iter().rev().take(2).product()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let monkey_chunks = aoc_core::blocks(data).map(|s| s.trim()).collect::<Vec<_>>();
+
+        monkey_chunks
+            .iter()
+            .enumerate()
+            .map(|(id, chunk)| Monkey::from_chunk(chunk, id, monkey_chunks.len()))
+            .collect()
+    }
+
+    fn part_1(monkeys: &Self::Input) -> Self::Part1 {
+        monkey_business(monkeys, 20, 3)
+    }
+
+    fn part_2(monkeys: &Self::Input) -> Self::Part2 {
+        monkey_business(monkeys, 10_000, 1)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // distinct primes keep the worry levels below their product small enough
+        let mut divisors = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut divisors);
+
+        let count = size.clamp(3, 8);
+        let monkeys = (0..count).map(|id| {
+            let items = (0..rng.range(1..5))
+                .map(|_| rng.range(50..100).to_string())
+                .collect::<Vec<_>>();
+            let operation = match rng.range(0..5) {
+                0 => "old * old".to_string(),
+                1 | 2 => format!("old * {}", rng.range(2..20)),
+                _ => format!("old + {}", rng.range(1..9)),
+            };
+
+            let if_true = (id + rng.range(1..count)) % count;
+            let mut if_false = if_true;
+
+            while if_false == if_true || if_false == id {
+                if_false = rng.range(0..count);
+            }
+
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                id,
+                items.join(", "),
+                operation,
+                divisors[id],
+                if_true,
+                if_false
+            )
+        });
+
+        Some(monkeys.collect::<Vec<_>>().join("\n"))
+    }
+}
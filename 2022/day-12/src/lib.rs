@@ -0,0 +1,323 @@
+use aoc_core::{
+    grid::Grid,
+    image::{Colormap, Image, Rgb},
+    point::{Direction, Point},
+    random::Rng,
+    search::{bfs, layers},
+    visual::{Canvas, Frame},
+    ParseError, Solution,
+};
+use std::{fmt, ops::Sub};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Elevation {
+    Start,
+    End,
+    Value(i32),
+}
+
+impl Elevation {
+    fn new(s: &str) -> Result<Self, ParseError> {
+        match s.chars().next() {
+            Some('S') => Ok(Self::Start),
+            Some('E') => Ok(Self::End),
+            Some(c @ 'a'..='z') => Ok(Self::Value(c as i32 - 97)),
+            _ => Err(ParseError::new(
+                s,
+                "expected a lowercase letter, `S` or `E`",
+            )),
+        }
+    }
+}
+
+impl Sub for Elevation {
+    type Output = i32;
+
+    fn sub(self, other: Self) -> Self::Output {
+        match (self, other) {
+            (Self::Value(a), Self::Value(b)) => a - b,
+            _ => panic!("Invalid subtraction"),
+        }
+    }
+}
+
+/// Checks that `marker` is on exactly one square of the heightmap.
+fn find_once(data: &str, marker: &str, name: &str) -> Result<(), ParseError> {
+    let mut found = data.match_indices(marker).map(|(i, _)| &data[i..i + 1]);
+
+    match (found.next(), found.next()) {
+        (Some(_), None) => Ok(()),
+        (None, _) => Err(ParseError::new(
+            data.lines().last().unwrap_or(data),
+            format!("expected the {} `{}`", name, marker),
+        )),
+        (Some(_), Some(second)) => Err(ParseError::new(
+            second,
+            format!("there can only be one {} `{}`", name, marker),
+        )),
+    }
+}
+
+fn replace_start_end(grid: &mut Grid<Elevation>) -> (Point, Point) {
+    let mut start = Point::ORIGIN;
+    let mut end = Point::ORIGIN;
+
+    for (point, elevation) in grid.iter_mut() {
+        // replace start and end with values
+        if *elevation == Elevation::Start {
+            start = point;
+            *elevation = Elevation::Value(0);
+        } else if *elevation == Elevation::End {
+            end = point;
+            *elevation = Elevation::Value(25);
+        }
+    }
+
+    (start, end)
+}
+
+/// The squares that can be climbed to from `point`, at most one higher.
+fn climbable(grid: &Grid<Elevation>, point: Point) -> impl Iterator<Item = Point> + '_ {
+    grid.neighbours(point)
+        .filter(move |&next| grid[next] - grid[point] <= 1)
+}
+
+/// The squares where the search starts for `part`.
+fn starts(heightmap: &Heightmap, part: u8) -> Vec<Point> {
+    match part {
+        1 => vec![heightmap.start],
+        _ => heightmap
+            .grid
+            .iter()
+            .filter(|(_, &elevation)| elevation == Elevation::Value(0))
+            .map(|(point, _)| point)
+            .collect(),
+    }
+}
+
+/// Draws the heightmap with the squares already `reached` as `.` and the `frontier` as `@`.
+fn draw_search(heightmap: &Heightmap, reached: &[Point], frontier: &[Point]) -> Canvas {
+    let grid = &heightmap.grid;
+    let mut canvas = Canvas::new(grid.columns(), grid.rows());
+
+    for (point, elevation) in grid.iter() {
+        if let Elevation::Value(value) = elevation {
+            canvas.draw(point, (b'a' + *value as u8) as char);
+        }
+    }
+
+    for &point in reached {
+        canvas.draw(point, '.');
+    }
+
+    for &point in frontier {
+        canvas.draw(point, '@');
+    }
+
+    canvas.draw(heightmap.end, 'E');
+    canvas
+}
+
+/// The length of the shortest path to the end, if there is one.
+pub struct Steps(Option<usize>);
+
+impl fmt::Display for Steps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(steps) => write!(f, "{}", steps),
+            None => write!(f, "unreachable"),
+        }
+    }
+}
+
+pub struct Heightmap {
+    grid: Grid<Elevation>,
+    start: Point,
+    end: Point,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 12;
+
+    type Input = Heightmap;
+    type Part1 = Steps;
+    type Part2 = Steps;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let mut grid = Grid::parse(data, Elevation::new)?;
+        find_once(data, "S", "start")?;
+        find_once(data, "E", "end")?;
+
+        let (start, end) = replace_start_end(&mut grid);
+
+        Ok(Heightmap { grid, start, end })
+    }
+
+    fn part_1(heightmap: &Self::Input) -> Self::Part1 {
+        let path = bfs(
+            starts(heightmap, 1),
+            |&point| climbable(&heightmap.grid, point),
+            |&point| point == heightmap.end,
+        );
+
+        Steps(path.map(|path| path.cost))
+    }
+
+    fn part_2(heightmap: &Self::Input) -> Self::Part2 {
+        let path = bfs(
+            starts(heightmap, 2),
+            |&point| climbable(&heightmap.grid, point),
+            |&point| point == heightmap.end,
+        );
+
+        Steps(path.map(|path| path.cost))
+    }
+
+    fn visualize(heightmap: &Self::Input, part: u8, show: &mut dyn FnMut(Frame)) -> bool {
+        let climb = |point: &Point| climbable(&heightmap.grid, *point);
+        let mut reached = vec![];
+
+        for (distance, frontier) in layers(starts(heightmap, part), climb).enumerate() {
+            let caption = format!("{} steps, {} squares reached", distance, reached.len());
+            show(Frame::new(
+                caption,
+                draw_search(heightmap, &reached, &frontier),
+            ));
+
+            if frontier.contains(&heightmap.end) {
+                break;
+            }
+
+            reached.extend(frontier);
+        }
+
+        if let Some(path) = bfs(starts(heightmap, part), climb, |&p| p == heightmap.end) {
+            let mut canvas = draw_search(heightmap, &[], &[]);
+
+            for step in path.states.windows(2) {
+                let direction = Direction::ALL
+                    .into_iter()
+                    .find(|direction| step[0] + direction.vector() == step[1]);
+                let arrow = match direction {
+                    Some(Direction::Up) => '^',
+                    Some(Direction::Right) => '>',
+                    Some(Direction::Down) => 'v',
+                    _ => '<',
+                };
+
+                canvas.draw(step[0], arrow);
+            }
+
+            show(Frame::new(
+                format!("Shortest path: {} steps", path.cost),
+                canvas,
+            ));
+        }
+
+        true
+    }
+
+    fn images(heightmap: &Self::Input) -> Vec<(&'static str, Image)> {
+        let elevation = Image::from_grid(&heightmap.grid, |&elevation| match elevation {
+            Elevation::Value(value) => Colormap::TERRAIN.scale(value as f64, 0.0, 25.0),
+            _ => Rgb::BLACK,
+        });
+        let mut path = elevation.clone();
+
+        let shortest = bfs(
+            starts(heightmap, 1),
+            |&point| climbable(&heightmap.grid, point),
+            |&point| point == heightmap.end,
+        );
+
+        for &point in shortest.iter().flat_map(|path| &path.states) {
+            path.set(point, Rgb::RED);
+        }
+
+        path.set(heightmap.start, Rgb::WHITE);
+        path.set(heightmap.end, Rgb::BLACK);
+
+        vec![("elevation", elevation), ("path", path)]
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let columns = size.max(8);
+        let rows = (columns / 2).max(5);
+        let letters = ('a'..='z').collect::<Vec<_>>();
+        let mut squares = (0..rows)
+            .map(|_| {
+                (0..columns)
+                    .map(|_| *rng.pick(&letters))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // a trail winding through every other row climbs from `S` to `E`,
+        // so that the summit can always be reached
+        let mut trail = vec![];
+
+        for y in (0..rows).step_by(2) {
+            let xs = (0..columns).collect::<Vec<_>>();
+
+            match (y / 2) % 2 {
+                0 => trail.extend(xs.into_iter().map(|x| (x, y))),
+                _ => trail.extend(xs.into_iter().rev().map(|x| (x, y))),
+            }
+
+            if y + 2 < rows {
+                let &(x, _) = trail.last().unwrap();
+                trail.push((x, y + 1));
+            }
+        }
+
+        let flip_x = rng.chance(0.5);
+        let flip_y = rng.chance(0.5);
+        let steps = trail.len() - 1;
+
+        for (i, &(x, y)) in trail.iter().enumerate() {
+            let x = if flip_x { columns - 1 - x } else { x };
+            let y = if flip_y { rows - 1 - y } else { y };
+
+            squares[y][x] = match i {
+                0 => 'S',
+                _ if i == steps => 'E',
+                _ => letters[i * 25 / steps],
+            };
+        }
+
+        let lines = squares
+            .into_iter()
+            .map(|row| row.into_iter().collect::<String>() + "\n");
+
+        Some(lines.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_and_end_once() {
+        let error = |data| Day12::parse(data).err().unwrap().locate(data);
+
+        assert_eq!(error("Sbc\nabc\n").message(), "expected the end `E`");
+        assert_eq!(error("abc\nabE\n").message(), "expected the start `S`");
+
+        let twice = error("SbE\nabS\n");
+
+        assert_eq!((twice.line(), twice.column()), (Some(2), Some(3)));
+        assert_eq!(twice.message(), "there can only be one start `S`");
+    }
+
+    #[test]
+    fn test_unreachable_end() {
+        let heightmap = Day12::parse("Sbc\nxyE\n").unwrap();
+
+        assert_eq!(Day12::part_1(&heightmap).to_string(), "unreachable");
+        assert_eq!(Day12::part_2(&heightmap).to_string(), "unreachable");
+    }
+}
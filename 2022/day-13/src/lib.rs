@@ -0,0 +1,181 @@
+use aoc_core::{parse, random::Rng, ParseError, Solution};
+use serde::Deserialize;
+use std::cmp::Ordering;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum Packet {
+    Value(usize),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    fn new(s: &str) -> Result<Self, ParseError> {
+        serde_json::from_str(s).map_err(|_| ParseError::new(s, "expected a packet"))
+    }
+
+    fn single_value_list(val: usize) -> Self {
+        Packet::List(vec![Packet::Value(val)])
+    }
+}
+
+// a number equals the list holding only that number, so packets are only
+// equal when they are in the right order both ways
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use Packet::*;
+        match (self, other) {
+            (Value(a), List(_)) => Packet::single_value_list(*a).cmp(other),
+            (List(_), Value(b)) => self.cmp(&Packet::single_value_list(*b)),
+            (Value(a), Value(b)) => a.cmp(b),
+            (List(a), List(b)) => {
+                for i in 0..a.len().min(b.len()) {
+                    let order = a[i].cmp(&b[i]);
+
+                    if order == Ordering::Equal {
+                        continue;
+                    }
+
+                    return order;
+                }
+
+                a.len().cmp(&b.len())
+            }
+        }
+    }
+}
+
+fn parse_pairs(data: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
+    aoc_core::blocks(data)
+        .map(|pair| {
+            let (left, right) = parse::split_once(pair, "\n")?;
+            let left: Packet = Packet::new(left)?;
+            let right: Packet = Packet::new(right)?;
+
+            Ok((left, right))
+        })
+        .collect()
+}
+
+/// A list of numbers and lists, nested at most `depth` more times.
+fn random_packet(rng: &mut Rng, depth: usize) -> String {
+    let items = (0..rng.range(0..5))
+        .map(|_| match depth > 0 && rng.chance(0.3) {
+            true => random_packet(rng, depth - 1),
+            false => rng.range(0..11).to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    format!("[{}]", items.join(","))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
+
+    type Input = Vec<(Packet, Packet)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_pairs(data)
+    }
+
+    fn part_1(pairs: &Self::Input) -> Self::Part1 {
+        pairs.iter().enumerate().fold(0, |mut acc, (i, pair)| {
+            let (left, right) = pair;
+
+            if left < right {
+                acc += i + 1;
+            }
+
+            acc
+        })
+    }
+
+    fn part_2(pairs: &Self::Input) -> Self::Part2 {
+        let list_list_val = |val: usize| Packet::List(vec![Packet::List(vec![Packet::Value(val)])]);
+        let divider_packets = [list_list_val(2), list_list_val(6)];
+        let packets = pairs.iter().flat_map(|(left, right)| [left, right]);
+
+        // a divider goes after the packets in the right order with it, and the
+        // second one after the first
+        divider_packets
+            .iter()
+            .enumerate()
+            .map(|(i, divider)| packets.clone().filter(|&packet| packet <= divider).count() + i + 1)
+            .product()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let pairs = (0..size.max(1))
+            .map(|_| format!("{}\n{}\n", random_packet(rng, 4), random_packet(rng, 4)))
+            .collect::<Vec<_>>();
+
+        Some(pairs.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn random(rng: &mut Rng) -> Packet {
+        Packet::new(&random_packet(rng, 3)).unwrap()
+    }
+
+    /// The same packet with some numbers wrapped in lists of their own.
+    fn wrap_numbers(packet: &Packet, rng: &mut Rng) -> Packet {
+        match packet {
+            Packet::Value(n) if rng.chance(0.5) => Packet::single_value_list(*n),
+            Packet::Value(n) => Packet::Value(*n),
+            Packet::List(items) => {
+                Packet::List(items.iter().map(|item| wrap_numbers(item, rng)).collect())
+            }
+        }
+    }
+
+    #[test]
+    fn test_order_is_total() {
+        for seed in 0..500 {
+            let mut rng = Rng::new(seed);
+            let [a, b, c] = [random(&mut rng), random(&mut rng), random(&mut rng)];
+
+            assert_eq!(a.cmp(&a), Ordering::Equal, "seed {}", seed);
+            assert_eq!(a.cmp(&b), b.cmp(&a).reverse(), "seed {}", seed);
+
+            if a <= b && b <= c {
+                assert!(a <= c, "seed {}", seed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_order_is_consistent_with_eq() {
+        for seed in 0..500 {
+            let mut rng = Rng::new(seed);
+            let [a, b] = [random(&mut rng), random(&mut rng)];
+            let wrapped = wrap_numbers(&a, &mut rng);
+
+            assert_eq!(a == b, a.cmp(&b) == Ordering::Equal, "seed {}", seed);
+            assert_eq!(a, wrapped, "seed {}", seed);
+            assert_eq!(a.cmp(&wrapped), Ordering::Equal, "seed {}", seed);
+        }
+    }
+}
@@ -0,0 +1,359 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    point::{Direction, Point, Vec2},
+    ParseError,
+};
+
+/// Steps to the orthogonal and diagonal neighbours, clockwise from above.
+const ADJACENT: [Vec2; 8] = [
+    Vec2::new(0, -1),
+    Vec2::new(1, -1),
+    Vec2::new(1, 0),
+    Vec2::new(1, 1),
+    Vec2::new(0, 1),
+    Vec2::new(-1, 1),
+    Vec2::new(-1, 0),
+    Vec2::new(-1, -1),
+];
+
+/// A rectangular grid of cells stored row by row, `x` being the column and `y` the row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    columns: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, row by row.
+    ///
+    /// Panics if there are not `rows * columns` cells.
+    pub fn new(rows: usize, columns: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), rows * columns, "Grid cells do not fit");
+
+        Self {
+            cells,
+            rows,
+            columns,
+        }
+    }
+
+    pub fn filled(rows: usize, columns: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(rows, columns, vec![value; rows * columns])
+    }
+
+    /// Parses a map with one character per cell. `cell` gets the character as
+    /// a slice of `data`, so that its errors point at the right place.
+    pub fn parse<F>(data: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(&str) -> Result<T, ParseError>,
+    {
+        let mut cells = vec![];
+        let mut rows = 0;
+        let mut columns = 0;
+
+        for line in data.lines() {
+            let before = cells.len();
+
+            for (i, c) in line.char_indices() {
+                cells.push(cell(&line[i..i + c.len_utf8()])?);
+            }
+
+            let width = cells.len() - before;
+
+            if rows == 0 {
+                columns = width;
+            } else if width != columns {
+                return Err(ParseError::new(
+                    line,
+                    format!("expected a row of {} cells", columns),
+                ));
+            }
+
+            rows += 1;
+        }
+
+        if columns == 0 {
+            return Err(ParseError::new(data, "expected a grid"));
+        }
+
+        Ok(Self::new(rows, columns, cells))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.columns as i64).contains(&point.x) && (0..self.rows as i64).contains(&point.y)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point).then(|| &self.cells[self.index(point)])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            let index = self.index(point);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// The point one `step` away, if it is still inside the grid.
+    pub fn step(&self, point: Point, step: Vec2) -> Option<Point> {
+        let next = point + step;
+
+        self.contains(next).then_some(next)
+    }
+
+    /// The neighbours above, to the right, below and to the left that are inside the grid.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction.vector()))
+    }
+
+    /// Like [`Grid::neighbours`], including the diagonal ones.
+    pub fn neighbours_8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ADJACENT
+            .into_iter()
+            .filter_map(move |step| self.step(point, step))
+    }
+
+    /// The points from `point` to the edge of the grid in the direction of
+    /// `step`, nearest first and without `point` itself.
+    pub fn ray(&self, point: Point, step: Vec2) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(self.step(point, step), move |&p| self.step(p, step))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "row {} is outside the grid", row);
+
+        &self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = &T> {
+        // past the last column, the cells would wrap around to the next rows
+        assert!(
+            column < self.columns,
+            "column {} is outside the grid",
+            column
+        );
+
+        self.cells[column..].iter().step_by(self.columns)
+    }
+
+    /// Every point, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let columns = self.columns;
+
+        (0..self.cells.len()).map(move |i| point_at(i, columns))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(&mut self.cells)
+    }
+
+    /// The point of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.rows, self.columns, self.cells.iter().map(f).collect())
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.columns, self.rows, |p| Point::new(p.y, p.x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_row = self.rows as i64 - 1;
+
+        self.rearrange(self.columns, self.rows, |p| Point::new(p.y, last_row - p.x))
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_column = self.columns as i64 - 1;
+
+        self.rearrange(self.columns, self.rows, |p| {
+            Point::new(last_column - p.y, p.x)
+        })
+    }
+
+    /// Builds a `rows` by `columns` grid where every cell comes from the
+    /// point `source` maps it to in this grid.
+    fn rearrange(&self, rows: usize, columns: usize, source: impl Fn(Point) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..rows * columns)
+            .map(|i| self[source(point_at(i, columns))].clone())
+            .collect();
+
+        Self::new(rows, columns, cells)
+    }
+
+    fn index(&self, point: Point) -> usize {
+        point.y as usize * self.columns + point.x as usize
+    }
+}
+
+fn point_at(index: usize, columns: usize) -> Point {
+    Point::new((index % columns) as i64, (index / columns) as i64)
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{} is outside the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{} is outside the grid", point))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in self.row(row) {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters(data: &str) -> Grid<char> {
+        Grid::parse(data, |s| Ok(s.chars().next().unwrap())).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = letters("abc\ndef\n");
+
+        assert_eq!((grid.rows(), grid.columns()), (2, 3));
+        assert_eq!(grid[Point::new(0, 1)], 'd');
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside the grid")]
+    fn test_column_outside() {
+        letters("abc\ndef\n").column(3).count();
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 is outside the grid")]
+    fn test_row_outside() {
+        letters("abc\ndef\n").row(2);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let data = "abc\nde\n";
+        let error = Grid::parse(data, |s| Ok(s.to_string()))
+            .unwrap_err()
+            .locate(data);
+
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.message(), "expected a row of 3 cells");
+
+        let error = Grid::<u32>::parse(data, crate::parse::number)
+            .unwrap_err()
+            .locate(data);
+
+        assert_eq!((error.line(), error.column()), (Some(1), Some(1)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = letters("abc\ndef\nghi");
+
+        assert_eq!(
+            grid.neighbours(Point::ORIGIN).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours_8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours_8(Point::new(2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = letters("abc\ndef\nghi");
+
+        assert_eq!(
+            grid.ray(Point::new(1, 2), Direction::Up.vector())
+                .map(|p| grid[p])
+                .collect::<String>(),
+            "eb"
+        );
+        assert_eq!(
+            grid.ray(Point::ORIGIN, Vec2::new(1, 1))
+                .map(|p| grid[p])
+                .collect::<String>(),
+            "ei"
+        );
+        assert_eq!(grid.ray(Point::ORIGIN, Direction::Left.vector()).count(), 0);
+    }
+
+    #[test]
+    fn test_rearrange() {
+        let grid = letters("abc\ndef");
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.position(|&c| c == 'e'), Some(Point::new(1, 1)));
+    }
+}
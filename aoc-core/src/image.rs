@@ -0,0 +1,275 @@
+use std::{borrow::Cow, fmt::Write};
+
+use crate::{grid::Grid, point::Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+    pub const RED: Self = Self(220, 40, 40);
+
+    /// The CSS notation, `#rrggbb`.
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+
+    fn mix(self, other: Self, t: f64) -> Self {
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+        Self(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+}
+
+/// A gradient through evenly spaced colors, to turn numbers into colors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Colormap {
+    stops: Cow<'static, [Rgb]>,
+}
+
+impl Colormap {
+    pub const GRAYSCALE: Self = Self::fixed(&[Rgb::BLACK, Rgb::WHITE]);
+    /// From sea level green to mountain brown and snow white.
+    pub const TERRAIN: Self = Self::fixed(&[
+        Rgb(34, 102, 51),
+        Rgb(119, 170, 68),
+        Rgb(204, 187, 102),
+        Rgb(136, 85, 51),
+        Rgb(245, 245, 245),
+    ]);
+    /// From cold dark blue to hot yellow.
+    pub const HEAT: Self = Self::fixed(&[
+        Rgb(12, 12, 60),
+        Rgb(140, 20, 120),
+        Rgb(230, 80, 30),
+        Rgb(255, 230, 80),
+    ]);
+
+    /// Panics without any color.
+    pub fn new(stops: Vec<Rgb>) -> Self {
+        assert!(!stops.is_empty(), "A colormap needs a color");
+
+        Self {
+            stops: Cow::Owned(stops),
+        }
+    }
+
+    const fn fixed(stops: &'static [Rgb]) -> Self {
+        Self {
+            stops: Cow::Borrowed(stops),
+        }
+    }
+
+    /// The color at `t` between 0 and 1, anything outside being clamped.
+    pub fn at(&self, t: f64) -> Rgb {
+        let last = self.stops.len() - 1;
+        let position = t.clamp(0.0, 1.0) * last as f64;
+        let i = (position.floor() as usize).min(last.saturating_sub(1));
+
+        match last {
+            0 => self.stops[0],
+            _ => self.stops[i].mix(self.stops[i + 1], position - i as f64),
+        }
+    }
+
+    /// The color of `value` when `min` and `max` are the ends of the gradient.
+    pub fn scale(&self, value: f64, min: f64, max: f64) -> Rgb {
+        if max <= min {
+            return self.at(0.0);
+        }
+
+        self.at((value - min) / (max - min))
+    }
+}
+
+/// A picture of a puzzle, one pixel per cell or point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// One pixel per cell, colored by `color`.
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl FnMut(&T) -> Rgb) -> Self {
+        Self {
+            width: grid.columns(),
+            height: grid.rows(),
+            pixels: grid.iter().map(|(_, cell)| cell).map(color).collect(),
+        }
+    }
+
+    /// The smallest image holding all the `points`, drawn in `foreground`.
+    pub fn from_points(points: &[Point], foreground: Rgb, background: Rgb) -> Self {
+        let min_x = points.iter().map(|p| p.x).min().unwrap_or(0);
+        let max_x = points.iter().map(|p| p.x).max().unwrap_or(-1);
+        let min_y = points.iter().map(|p| p.y).min().unwrap_or(0);
+        let max_y = points.iter().map(|p| p.y).max().unwrap_or(-1);
+
+        let origin = Point::new(min_x, min_y);
+        let mut image = Self::new(
+            (max_x - min_x + 1) as usize,
+            (max_y - min_y + 1) as usize,
+            background,
+        );
+
+        for &point in points {
+            image.set(point - origin, foreground);
+        }
+
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The pixels row by row.
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    pub fn get(&self, point: Point) -> Option<Rgb> {
+        self.index(point).map(|i| self.pixels[i])
+    }
+
+    /// Colors a pixel, anything outside of the image being cut off.
+    pub fn set(&mut self, point: Point, color: Rgb) {
+        if let Some(i) = self.index(point) {
+            self.pixels[i] = color;
+        }
+    }
+
+    /// Every pixel blown up to a `factor` by `factor` square.
+    pub fn scaled(&self, factor: usize) -> Self {
+        let factor = factor.max(1);
+        let width = self.width * factor;
+        let pixels = (0..self.height * factor)
+            .flat_map(|y| (0..width).map(move |x| (x / factor, y / factor)))
+            .map(|(x, y)| self.pixels[y * self.width + x])
+            .collect();
+
+        Self {
+            width,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    /// A binary PPM (`P6`), the simplest format any image viewer reads.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+
+        for &Rgb(r, g, b) in &self.pixels {
+            ppm.extend([r, g, b]);
+        }
+
+        ppm
+    }
+
+    /// An SVG with one rectangle per run of equal pixels in a row.
+    pub fn to_svg(&self) -> String {
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\" shape-rendering=\"crispEdges\">\n",
+            self.width, self.height
+        );
+
+        for y in 0..self.height {
+            let row = &self.pixels[y * self.width..(y + 1) * self.width];
+            let mut x = 0;
+
+            for run in row.chunk_by(|a, b| a == b) {
+                writeln!(
+                    svg,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\" fill=\"{}\"/>",
+                    x,
+                    y,
+                    run.len(),
+                    run[0].hex()
+                )
+                .unwrap();
+
+                x += run.len();
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        let inside =
+            (0..self.width as i64).contains(&point.x) && (0..self.height as i64).contains(&point.y);
+
+        inside.then(|| point.y as usize * self.width + point.x as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colormap() {
+        let colormap = Colormap::new(vec![Rgb::BLACK, Rgb(200, 100, 0), Rgb::WHITE]);
+
+        assert_eq!(colormap.at(0.0), Rgb::BLACK);
+        assert_eq!(colormap.at(0.25), Rgb(100, 50, 0));
+        assert_eq!(colormap.at(1.5), Rgb::WHITE);
+        assert_eq!(
+            Colormap::GRAYSCALE.scale(5.0, 0.0, 10.0),
+            Rgb(128, 128, 128)
+        );
+        assert_eq!(Colormap::new(vec![Rgb::RED]).at(0.7), Rgb::RED);
+    }
+
+    #[test]
+    fn test_from_points() {
+        let points = [Point::new(-1, 2), Point::new(1, 3)];
+        let image = Image::from_points(&points, Rgb::WHITE, Rgb::BLACK);
+
+        assert_eq!((image.width(), image.height()), (3, 2));
+        assert_eq!(image.get(Point::new(0, 0)), Some(Rgb::WHITE));
+        assert_eq!(image.get(Point::new(2, 1)), Some(Rgb::WHITE));
+        assert_eq!(image.get(Point::new(1, 0)), Some(Rgb::BLACK));
+    }
+
+    #[test]
+    fn test_encodings() {
+        let grid = Grid::new(1, 2, vec![true, false]);
+        let image = Image::from_grid(&grid, |&on| if on { Rgb::WHITE } else { Rgb::BLACK });
+
+        assert_eq!(
+            image.to_ppm(),
+            b"P6\n2 1\n255\n\xff\xff\xff\x00\x00\x00".to_vec()
+        );
+        assert!(image
+            .to_svg()
+            .contains("<rect x=\"1\" y=\"0\" width=\"1\" height=\"1\" fill=\"#000000\"/>"));
+
+        let scaled = image.scaled(2);
+
+        assert_eq!((scaled.width(), scaled.height()), (4, 2));
+        assert_eq!(scaled.get(Point::new(1, 1)), Some(Rgb::WHITE));
+        assert_eq!(scaled.get(Point::new(2, 0)), Some(Rgb::BLACK));
+        assert_eq!(scaled.to_svg().matches("<rect").count(), 4);
+    }
+}
@@ -0,0 +1,234 @@
+use std::{
+    convert::Infallible,
+    error::Error,
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::Puzzle;
+
+/// The directory holding the puzzle inputs, kept out of the day crates.
+pub const INPUTS_DIR: &str = "inputs";
+
+/// The year of the inputs and answers saved directly in [`INPUTS_DIR`], before
+/// the years were split.
+pub const LEGACY_YEAR: u16 = 2022;
+
+/// Where to read the puzzle input of a day from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// `inputs/YYYY/day-NN.txt`
+    #[default]
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Self::Stdin,
+            path => Self::Path(PathBuf::from(path)),
+        })
+    }
+}
+
+/// How much of an input's whitespace is cleaned up before it is parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalize {
+    /// Unix line endings, no trailing whitespace on any line and no trailing blank lines.
+    /// Leading blank lines are kept so that errors point at the lines of the file,
+    /// and are skipped when the input is parsed.
+    Full,
+    /// Unix line endings and no trailing blank lines, for inputs where spaces are meaningful.
+    LineEndings,
+}
+
+/// Cleans up an input saved by another editor or platform, ending it with a single newline.
+pub fn normalize(data: &str, normalize: Normalize) -> String {
+    let mut lines = data
+        .lines()
+        .map(|line| match normalize {
+            Normalize::Full => line.trim_end(),
+            Normalize::LineEndings => line,
+        })
+        .collect::<Vec<_>>();
+
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let mut normalized = lines.join("\n");
+
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+
+    normalized
+}
+
+impl InputSource {
+    /// Reads the input of a puzzle, normalized the way the puzzle expects it.
+    pub fn load(&self, puzzle: &dyn Puzzle) -> Result<String, InputError> {
+        let data = self.read(puzzle.year(), puzzle.day())?;
+
+        Ok(normalize(&data, puzzle.normalize()))
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String, InputError> {
+        match self {
+            Self::Default => read_file(&default_path(year, day)),
+            Self::Path(path) => read_file(path),
+            Self::Stdin => {
+                let mut data = String::new();
+
+                io::stdin()
+                    .read_to_string(&mut data)
+                    .map_err(InputError::Stdin)?;
+
+                Ok(data)
+            }
+        }
+    }
+}
+
+/// Where the inputs and answers of a year are kept.
+pub fn year_dir(year: u16) -> PathBuf {
+    Path::new(INPUTS_DIR).join(year.to_string())
+}
+
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    year_dir(year).join(format!("day-{:02}.txt", day))
+}
+
+/// The files of `inputs` saved before the years were split, with where they
+/// belong now. A file that is also in its new place is left alone.
+pub fn legacy_files(inputs: &Path) -> io::Result<Vec<(PathBuf, PathBuf)>> {
+    let entries = match fs::read_dir(inputs) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut files = vec![];
+
+    for entry in entries {
+        let from = entry?.path();
+        let Some(name) = from.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let to = inputs.join(LEGACY_YEAR.to_string()).join(name);
+        let known = name.starts_with("day-") && name.ends_with(".txt")
+            || ["answers.json", "submissions.json"].contains(&name);
+
+        if known && from.is_file() && !to.exists() {
+            files.push((from, to));
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| InputError::File {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    File { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File { path, source } => {
+                write!(f, "unable to read input {}: {}", path.display(), source)
+            }
+            Self::Stdin(source) => write!(f, "unable to read input from stdin: {}", source),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::File { source, .. } | Self::Stdin(source) => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_path() {
+        assert_eq!(
+            default_path(2022, 7),
+            PathBuf::from("inputs/2022/day-07.txt")
+        );
+    }
+
+    #[test]
+    fn test_parse_source() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "my/input.txt".parse(),
+            Ok(InputSource::Path(PathBuf::from("my/input.txt")))
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let data = "\r\n1 2  \r\n\r\n  3\t\r\n\r\n\n";
+
+        assert_eq!(normalize(data, Normalize::Full), "\n1 2\n\n  3\n");
+        assert_eq!(
+            normalize(data, Normalize::LineEndings),
+            "\n1 2  \n\n  3\t\n"
+        );
+        assert_eq!(normalize("\n \n", Normalize::Full), "");
+    }
+
+    #[test]
+    fn test_legacy_files() {
+        let inputs = tempfile::tempdir().unwrap();
+        let inputs = inputs.path();
+
+        fs::create_dir(inputs.join("2022")).unwrap();
+
+        for name in ["day-01.txt", "day-02.txt", "answers.json", "notes.md"] {
+            fs::write(inputs.join(name), "").unwrap();
+        }
+
+        fs::write(inputs.join("2022/day-02.txt"), "").unwrap();
+
+        assert_eq!(
+            legacy_files(inputs).unwrap(),
+            vec![
+                (
+                    inputs.join("answers.json"),
+                    inputs.join("2022/answers.json")
+                ),
+                (inputs.join("day-01.txt"), inputs.join("2022/day-01.txt")),
+            ]
+        );
+        assert_eq!(legacy_files(&inputs.join("missing")).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_missing_file_names_path() {
+        let source = InputSource::Path(PathBuf::from("does/not/exist.txt"));
+        let error = source.read(2022, 1).unwrap_err();
+
+        assert!(error.to_string().contains("does/not/exist.txt"));
+    }
+}
@@ -0,0 +1,30 @@
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod point;
+pub mod random;
+pub mod search;
+mod solution;
+pub mod visual;
+
+pub use parse::ParseError;
+pub use solution::{Alternative, Parsed, Puzzle, Solution, REFERENCE};
+
+/// Splits the input into the chunks separated by blank lines.
+pub fn blocks(data: &str) -> impl Iterator<Item = &str> {
+    data.split("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks() {
+        let data = "1\n2\n\n3\n\n4";
+
+        assert_eq!(blocks(data).collect::<Vec<_>>(), vec!["1\n2", "3", "4"]);
+    }
+}
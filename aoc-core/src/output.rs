@@ -0,0 +1,28 @@
+/// Formats an answer as `Part N: answer`, putting multi-line answers
+/// (such as the CRT screen of day 10) on their own lines.
+pub fn format_answer(part: u8, answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("Part {}:\n{}", part, answer)
+    } else {
+        format!("Part {}: {}", part, answer)
+    }
+}
+
+pub fn print_answer(part: u8, answer: &str) {
+    println!("{}", format_answer(part, answer));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_line() {
+        assert_eq!(format_answer(1, "24000"), "Part 1: 24000");
+    }
+
+    #[test]
+    fn test_multi_line() {
+        assert_eq!(format_answer(2, "#.\n.#"), "Part 2:\n#.\n.#");
+    }
+}
@@ -0,0 +1,294 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// An error pointing at the offending text of a puzzle input.
+///
+/// Parsers only need the fragment of the input that is wrong; the line and
+/// column are found afterwards by [`ParseError::locate`], as long as the
+/// fragment is a slice of the input that was parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    year: Option<u16>,
+    day: Option<u8>,
+    location: Option<Location>,
+    text: String,
+    message: String,
+    address: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Location {
+    line: usize,
+    column: usize,
+    source_line: String,
+}
+
+impl ParseError {
+    pub fn new(fragment: &str, message: impl Into<String>) -> Self {
+        Self {
+            year: None,
+            day: None,
+            location: None,
+            text: fragment.to_string(),
+            message: message.into(),
+            address: fragment.as_ptr() as usize,
+        }
+    }
+
+    /// Finds the line and column of the offending fragment within `source`.
+    pub fn locate(mut self, source: &str) -> Self {
+        let start = source.as_ptr() as usize;
+
+        if self.location.is_some() || self.address < start || self.address > start + source.len() {
+            return self;
+        }
+
+        let offset = self.address - start;
+
+        if !source.is_char_boundary(offset) {
+            return self;
+        }
+
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+
+        self.location = Some(Location {
+            line: source[..offset].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            source_line: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        });
+
+        self
+    }
+
+    pub fn with_day(mut self, year: u16, day: u8) -> Self {
+        self.year = Some(year);
+        self.day = Some(day);
+        self
+    }
+
+    pub fn year(&self) -> Option<u16> {
+        self.year
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// The 1-based line of the offending text, once located.
+    pub fn line(&self) -> Option<usize> {
+        self.location.as_ref().map(|location| location.line)
+    }
+
+    /// The 1-based column of the offending text, once located.
+    pub fn column(&self) -> Option<usize> {
+        self.location.as_ref().map(|location| location.column)
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(year) = self.year {
+            write!(f, "{} ", year)?;
+        }
+
+        if let Some(day) = self.day {
+            write!(f, "day {:02}, ", day)?;
+        }
+
+        match &self.location {
+            Some(location) => write!(f, "line {}, column {}: ", location.line, location.column)?,
+            None => write!(f, "unknown location: ")?,
+        }
+
+        write!(f, "{} (found `{}`)", self.message, self.text)?;
+
+        if let Some(location) = &self.location {
+            let padding = " ".repeat(location.column - 1);
+            let width = self
+                .text
+                .lines()
+                .next()
+                .unwrap_or("")
+                .chars()
+                .count()
+                .max(1);
+
+            write!(
+                f,
+                "\n  | {}\n  | {}{}",
+                location.source_line,
+                padding,
+                "^".repeat(width)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a number, pointing at it when it is not one.
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse()
+        .map_err(|_| ParseError::new(s, format!("expected a {}", type_name::<T>())))
+}
+
+/// Splits `s` around the first `delimiter`, pointing at `s` when it is missing.
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(s, format!("expected `{}`", delimiter.escape_debug())))
+}
+
+/// Matches `s` against a `template` such as `"move {count} from {from} to {to}"`,
+/// returning the text of every `{field}` in order. The names only document the
+/// fields and name them in errors; a field ends where the text after it starts.
+///
+/// Panics if the template does not have `N` fields or two fields touch.
+pub fn pattern<'a, const N: usize>(s: &'a str, template: &str) -> Result<[&'a str; N], ParseError> {
+    let mut parts = template.split('{');
+    let prefix = parts.next().unwrap_or("");
+    let fields = parts
+        .map(|part| {
+            part.split_once('}')
+                .unwrap_or_else(|| panic!("Unclosed field in `{}`", template))
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(fields.len(), N, "`{}` does not have {} fields", template, N);
+
+    let mut rest = s
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(s, format!("expected `{}`", template)))?;
+    let mut values = [""; N];
+
+    for (i, (name, literal)) in fields.into_iter().enumerate() {
+        let end = if literal.is_empty() {
+            assert!(i == N - 1, "Fields must be separated in `{}`", template);
+            rest.len()
+        } else {
+            rest.find(literal)
+                .ok_or_else(|| ParseError::new(rest, format!("expected `{}`", literal)))?
+        };
+
+        if end == 0 {
+            return Err(ParseError::new(rest, format!("missing `{{{}}}`", name)));
+        }
+
+        values[i] = &rest[..end];
+        rest = &rest[end + literal.len()..];
+    }
+
+    if !rest.is_empty() {
+        return Err(ParseError::new(rest, "unexpected text"));
+    }
+
+    Ok(values)
+}
+
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+
+    match name {
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => "positive number",
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => "number",
+        _ => name.rsplit("::").next().unwrap_or(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let data = "2-4,6-8\n2-x,4-5";
+        let fragment = &data[10..11];
+        let error = ParseError::new(fragment, "expected a number")
+            .locate(data)
+            .with_day(2022, 4);
+
+        assert_eq!((error.year(), error.day()), (Some(2022), Some(4)));
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.column(), Some(3));
+        assert_eq!(error.text(), "x");
+        assert_eq!(
+            error.to_string(),
+            "2022 day 04, line 2, column 3: expected a number (found `x`)\n  | 2-x,4-5\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn test_foreign_fragment_is_not_located() {
+        let error = ParseError::new("x", "expected a number").locate("1\n2");
+
+        assert_eq!(error.line(), None);
+        assert_eq!(
+            error.to_string(),
+            "unknown location: expected a number (found `x`)"
+        );
+    }
+
+    #[test]
+    fn test_helpers() {
+        let data = "12 ab";
+
+        assert_eq!(number::<u32>(&data[..2]), Ok(12));
+        assert_eq!(
+            number::<u32>(&data[3..]).unwrap_err().locate(data).column(),
+            Some(4)
+        );
+        assert_eq!(split_once(data, " "), Ok(("12", "ab")));
+        assert_eq!(split_once(data, ",").unwrap_err().message(), "expected `,`");
+    }
+
+    #[test]
+    fn test_pattern() {
+        let template = "move {count} from {from} to {to}";
+
+        assert_eq!(
+            pattern("move 13 from 1 to 2", template),
+            Ok(["13", "1", "2"])
+        );
+        assert_eq!(pattern("addx -5", "addx {value}"), Ok(["-5"]));
+        assert_eq!(pattern("noop", "noop"), Ok([]));
+
+        let error = |data: &str| {
+            let error = pattern::<3>(data, template).unwrap_err().locate(data);
+            (error.column(), error.message().to_string())
+        };
+
+        assert_eq!(
+            error("mv 1 from 2 to 3"),
+            (Some(1), format!("expected `{}`", template))
+        );
+        assert_eq!(
+            error("move 1 fro 2 to 3"),
+            (Some(6), "expected ` from `".to_string())
+        );
+        assert_eq!(
+            error("move  from 2 to 3"),
+            (Some(6), "missing `{count}`".to_string())
+        );
+        assert_eq!(
+            error("move 1 from 2 to "),
+            (Some(18), "missing `{to}`".to_string())
+        );
+        assert_eq!(
+            pattern::<0>("noop 1", "noop").unwrap_err().message(),
+            "unexpected text"
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::ParseError;
+
+/// A point on a 2D plane, or the vector between two of them.
+///
+/// `y` grows downwards, like the rows of a [`Grid`](crate::grid::Grid).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+pub type Vec2 = Point;
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The number of orthogonal steps to `other`.
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The number of steps to `other` when diagonal steps are allowed.
+    pub fn chebyshev(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The single step, diagonal or not, in the direction of this vector.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self::Output {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// The step of length 1 in this direction.
+    pub fn vector(self) -> Vec2 {
+        match self {
+            Self::Up => Vec2::new(0, -1),
+            Self::Right => Vec2::new(1, 0),
+            Self::Down => Vec2::new(0, 1),
+            Self::Left => Vec2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    /// Reads `U`/`R`/`D`/`L`, the compass points `N`/`E`/`S`/`W` or the arrows `^`/`>`/`v`/`<`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" | "N" | "^" => Ok(Self::Up),
+            "R" | "E" | ">" => Ok(Self::Right),
+            "D" | "S" | "v" => Ok(Self::Down),
+            "L" | "W" | "<" => Ok(Self::Left),
+            _ => Err(ParseError::new(s, "expected a direction")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut point = Point::new(1, 2) + Vec2::new(3, -4) * 2;
+
+        assert_eq!(point, Point::new(7, -6));
+
+        point -= Point::new(7, 0);
+
+        assert_eq!(point, Point::new(0, -6));
+        assert_eq!(-point, Point::new(0, 6));
+        assert_eq!(point.signum(), Vec2::new(0, -1));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, 1);
+        let b = Point::new(4, -1);
+
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(b), 3);
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!("U".parse(), Ok(Direction::Up));
+        assert_eq!("<".parse(), Ok(Direction::Left));
+        assert!("X".parse::<Direction>().is_err());
+
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Down.opposite(), Direction::Up);
+        assert_eq!(
+            Direction::ALL
+                .into_iter()
+                .map(Direction::vector)
+                .fold(Vec2::ORIGIN, Add::add),
+            Vec2::ORIGIN
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use std::ops::Range;
+
+/// A small seeded random number generator (SplitMix64) for generating puzzle inputs.
+///
+/// It is not meant for anything but tests and benchmarks, and is kept here rather
+/// than taken from a crate so that a seed always generates the same input.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, panicking when it is empty.
+    pub fn range<T: Uniform>(&mut self, range: Range<T>) -> T {
+        assert!(range.start < range.end, "Cannot pick from an empty range");
+
+        T::between(self, range.start, range.end)
+    }
+
+    /// `true` with a probability of `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    /// A random element of `items`, panicking when there are none.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..i + 1));
+        }
+    }
+
+    /// A number below `bound`, with a bias too small to matter for inputs.
+    fn below(&mut self, bound: u64) -> u64 {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+}
+
+/// The integers [`Rng::range`] can pick from.
+pub trait Uniform: Copy + PartialOrd {
+    fn between(rng: &mut Rng, low: Self, high: Self) -> Self;
+}
+
+macro_rules! uniform {
+    ($($t:ty),*) => {
+        $(
+            impl Uniform for $t {
+                fn between(rng: &mut Rng, low: Self, high: Self) -> Self {
+                    let span = (high as i128 - low as i128) as u64;
+
+                    (low as i128 + rng.below(span) as i128) as Self
+                }
+            }
+        )*
+    };
+}
+
+uniform!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_is_deterministic() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(1);
+        let rolls = (0..1000).map(|_| rng.range(-3i32..3)).collect::<Vec<_>>();
+
+        assert!(rolls.iter().all(|roll| (-3..3).contains(roll)));
+        assert!((-3..3).all(|n| rolls.contains(&n)));
+        assert_eq!(rng.range(5usize..6), 5);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = Rng::new(2);
+        let mut items = (0..20).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
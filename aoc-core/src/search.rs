@@ -0,0 +1,300 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// The cheapest way found from one of the starts to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    /// The number of steps for [`bfs`], the summed step costs otherwise.
+    pub cost: usize,
+    /// Every state from the start to the goal, both included.
+    pub states: Vec<S>,
+}
+
+impl<S> Path<S> {
+    pub fn start(&self) -> &S {
+        &self.states[0]
+    }
+
+    pub fn goal(&self) -> &S {
+        &self.states[self.states.len() - 1]
+    }
+}
+
+/// Breadth first search from every start at once, where every step costs 1.
+///
+/// Returns `None` when no goal can be reached.
+pub fn bfs<S, N, I, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: N,
+    mut is_goal: G,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::default();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Some(id) = visited.insert(start, None, 0) {
+            queue.push_back(id);
+        }
+    }
+
+    while let Some(id) = queue.pop_front() {
+        if is_goal(&visited.states[id]) {
+            return Some(visited.path(id));
+        }
+
+        let cost = visited.costs[id] + 1;
+
+        for next in neighbours(&visited.states[id]) {
+            if let Some(next) = visited.insert(next, Some(id), cost) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's search from every start at once, the neighbours coming with the cost of the step.
+///
+/// Returns `None` when no goal can be reached.
+pub fn dijkstra<S, N, I, G>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: N,
+    is_goal: G,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(starts, neighbours, |_| 0, is_goal)
+}
+
+/// Like [`dijkstra`], guided by a `heuristic` that never overestimates the
+/// remaining cost to a goal.
+pub fn astar<S, N, I, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::default();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if let Some(id) = visited.insert(start, None, 0) {
+            heap.push(Reverse((heuristic(&visited.states[id]), 0, id)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, id))) = heap.pop() {
+        // a cheaper way to this state was queued after this one
+        if cost > visited.costs[id] {
+            continue;
+        }
+
+        if is_goal(&visited.states[id]) {
+            return Some(visited.path(id));
+        }
+
+        for (next, step) in neighbours(&visited.states[id]) {
+            let next_cost = cost + step;
+
+            if let Some(next) = visited.insert(next, Some(id), next_cost) {
+                let estimate = next_cost + heuristic(&visited.states[next]);
+                heap.push(Reverse((estimate, next_cost, next)));
+            }
+        }
+    }
+
+    None
+}
+
+/// The states reachable from the starts in order of distance, one layer of
+/// equally distant states at a time, starting with the starts themselves.
+pub fn layers<S, N, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: N,
+) -> impl Iterator<Item = Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = HashSet::new();
+    let mut layer = starts
+        .into_iter()
+        .filter(|start| visited.insert(start.clone()))
+        .collect::<Vec<_>>();
+
+    std::iter::from_fn(move || {
+        if layer.is_empty() {
+            return None;
+        }
+
+        let next = layer
+            .iter()
+            .flat_map(&mut neighbours)
+            .filter(|state| visited.insert(state.clone()))
+            .collect();
+
+        Some(std::mem::replace(&mut layer, next))
+    })
+}
+
+/// Every state reached so far with the cheapest known cost and the state it was reached from.
+/// States are numbered so that they only need to be hashable, not ordered.
+struct Visited<S> {
+    ids: HashMap<S, usize>,
+    states: Vec<S>,
+    costs: Vec<usize>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<S> Default for Visited<S> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            states: vec![],
+            costs: vec![],
+            parents: vec![],
+        }
+    }
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    /// Records `state`, returning its id when it is new or now cheaper to reach.
+    fn insert(&mut self, state: S, parent: Option<usize>, cost: usize) -> Option<usize> {
+        match self.ids.get(&state) {
+            Some(&id) if self.costs[id] <= cost => None,
+            Some(&id) => {
+                self.costs[id] = cost;
+                self.parents[id] = parent;
+                Some(id)
+            }
+            None => {
+                let id = self.states.len();
+
+                self.ids.insert(state.clone(), id);
+                self.states.push(state);
+                self.costs.push(cost);
+                self.parents.push(parent);
+
+                Some(id)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<S> {
+        let mut states = vec![];
+        let mut id = Some(goal);
+
+        while let Some(current) = id {
+            states.push(self.states[current].clone());
+            id = self.parents[current];
+        }
+
+        states.reverse();
+
+        Path {
+            cost: self.costs[goal],
+            states,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A weighted graph where the direct edge 0 -> 3 is more expensive than going around.
+    fn edges(node: &u8) -> Vec<(u8, usize)> {
+        match node {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 3)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs([0], |n| edges(n).into_iter().map(|(n, _)| n), |&n| n == 3).unwrap();
+
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.states, vec![0, 3]);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra([0], edges, |&n| n == 3).unwrap();
+
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states, vec![0, 1, 2, 3]);
+        assert_eq!((*path.start(), *path.goal()), (0, 3));
+    }
+
+    #[test]
+    fn test_astar() {
+        // steps of 1 on a line, the distance to the goal being a perfect heuristic
+        let path = astar(
+            [0_i32],
+            |&n| [(n - 1, 1), (n + 1, 1)],
+            |&n| n.abs_diff(5) as usize,
+            |&n| n == 5,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_multiple_starts() {
+        let path = dijkstra([0, 2], edges, |&n| n == 3).unwrap();
+
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.states, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_start_is_goal() {
+        let path = bfs([4], |_| [], |&n| n == 4).unwrap();
+
+        assert_eq!(path.cost, 0);
+        assert_eq!(path.states, vec![4]);
+    }
+
+    #[test]
+    fn test_layers() {
+        let layers = layers([0], |n| edges(n).into_iter().map(|(n, _)| n)).collect::<Vec<_>>();
+
+        assert_eq!(layers, vec![vec![0], vec![1, 3], vec![2]]);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        assert_eq!(dijkstra([3], edges, |&n| n == 0), None);
+        assert_eq!(
+            bfs([1], |&n| edges(&n).into_iter().map(|(n, _)| n), |&n| n == 0),
+            None
+        );
+    }
+}
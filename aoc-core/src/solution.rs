@@ -0,0 +1,243 @@
+use std::{any::Any, fmt::Display};
+
+use crate::{image::Image, input::Normalize, random::Rng, visual::Frame, ParseError};
+
+/// A day's puzzle: the input is parsed once and then shared by both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    /// Days whose input layout depends on spaces can opt out of the full normalization.
+    const NORMALIZE: Normalize = Normalize::Full;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(input: &Self::Input) -> Self::Part1;
+    fn part_2(input: &Self::Input) -> Self::Part2;
+
+    /// Shows how `part` is solved, one frame per step of the simulation.
+    /// Returns `false` for the days without a visualization.
+    fn visualize(_input: &Self::Input, _part: u8, _show: &mut dyn FnMut(Frame)) -> bool {
+        false
+    }
+
+    /// Pictures of the input or of the answers, each with a short name for its file.
+    fn images(_input: &Self::Input) -> Vec<(&'static str, Image)> {
+        Vec::new()
+    }
+
+    /// Other ways of solving the parts, checked against `part_1` and `part_2`
+    /// and benchmarked next to them.
+    fn alternatives() -> Vec<Alternative<Self::Input>> {
+        Vec::new()
+    }
+
+    /// A random but valid input, `size` being roughly its number of lines or records.
+    /// Returns `None` for the days without a generator.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+/// Another implementation of a part, such as a faster one, that lives next to the
+/// readable reference instead of replacing it.
+pub struct Alternative<I> {
+    pub name: &'static str,
+    pub part: u8,
+    pub solve: fn(&I) -> String,
+}
+
+/// The name of the `part_1` and `part_2` implementations among the alternatives.
+pub const REFERENCE: &str = "reference";
+
+/// The parsed input of a [`Puzzle`], only readable by the solution that parsed it.
+pub type Parsed = Box<dyn Any>;
+
+/// An object safe view of a [`Solution`] so that every day can be kept in one registry.
+pub trait Puzzle: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn normalize(&self) -> Normalize;
+    fn parse(&self, data: &str) -> Result<Parsed, ParseError>;
+    fn part_1(&self, input: &Parsed) -> String;
+    fn part_2(&self, input: &Parsed) -> String;
+    fn visualize(&self, input: &Parsed, part: u8, show: &mut dyn FnMut(Frame)) -> bool;
+    fn images(&self, input: &Parsed) -> Vec<(&'static str, Image)>;
+    /// The names of the implementations of `part`, the [`REFERENCE`] first.
+    fn implementations(&self, part: u8) -> Vec<&'static str>;
+    /// Solves `part` with the implementation called `name`, if there is one.
+    fn solve_with(&self, input: &Parsed, part: u8, name: &str) -> Option<String>;
+    /// The input generated from `seed`, always the same for the same seed and size.
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+}
+
+impl<S> Puzzle for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn normalize(&self) -> Normalize {
+        S::NORMALIZE
+    }
+
+    fn parse(&self, data: &str) -> Result<Parsed, ParseError> {
+        Ok(Box::new(parse::<S>(data)?))
+    }
+
+    fn part_1(&self, input: &Parsed) -> String {
+        S::part_1(downcast::<S>(input)).to_string()
+    }
+
+    fn part_2(&self, input: &Parsed) -> String {
+        S::part_2(downcast::<S>(input)).to_string()
+    }
+
+    fn visualize(&self, input: &Parsed, part: u8, show: &mut dyn FnMut(Frame)) -> bool {
+        S::visualize(downcast::<S>(input), part, show)
+    }
+
+    fn images(&self, input: &Parsed) -> Vec<(&'static str, Image)> {
+        S::images(downcast::<S>(input))
+    }
+
+    fn implementations(&self, part: u8) -> Vec<&'static str> {
+        let alternatives = S::alternatives().into_iter().filter(|a| a.part == part);
+
+        [REFERENCE]
+            .into_iter()
+            .chain(alternatives.map(|a| a.name))
+            .collect()
+    }
+
+    fn solve_with(&self, input: &Parsed, part: u8, name: &str) -> Option<String> {
+        let input = downcast::<S>(input);
+
+        match (name, part) {
+            (REFERENCE, 1) => Some(S::part_1(input).to_string()),
+            (REFERENCE, 2) => Some(S::part_2(input).to_string()),
+            _ => S::alternatives()
+                .into_iter()
+                .find(|a| a.name == name && a.part == part)
+                .map(|a| (a.solve)(input)),
+        }
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        S::generate(&mut Rng::new(seed), size)
+    }
+}
+
+/// Parses the input, locating any error within it and tagging it with the day.
+fn parse<S: Solution + ?Sized>(data: &str) -> Result<S::Input, ParseError> {
+    // the leading blank lines are only skipped here, so that the lines of the
+    // errors are still counted from the start of the file
+    let start = match S::NORMALIZE {
+        Normalize::Full => data.len() - data.trim_start_matches('\n').len(),
+        Normalize::LineEndings => 0,
+    };
+
+    S::parse(&data[start..]).map_err(|err| err.locate(data).with_day(S::YEAR, S::DAY))
+}
+
+fn downcast<S: Solution>(input: &Parsed) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .unwrap_or_else(|| panic!("Input was not parsed by day {}", S::DAY))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 1;
+
+        type Input = Vec<i32>;
+        type Part1 = i32;
+        type Part2 = String;
+
+        fn parse(data: &str) -> Result<Self::Input, ParseError> {
+            data.lines().map(crate::parse::number).collect()
+        }
+
+        fn part_1(input: &Self::Input) -> Self::Part1 {
+            input.iter().sum()
+        }
+
+        fn part_2(input: &Self::Input) -> Self::Part2 {
+            format!("{} numbers", input.len())
+        }
+
+        fn alternatives() -> Vec<Alternative<Self::Input>> {
+            vec![Alternative {
+                name: "backwards",
+                part: 1,
+                solve: |input| input.iter().rev().sum::<i32>().to_string(),
+            }]
+        }
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let puzzle: &dyn Puzzle = &Sum;
+        let input = puzzle.parse("4\n5").unwrap();
+
+        assert_eq!((puzzle.year(), puzzle.day()), (2022, 1));
+        assert_eq!(puzzle.part_1(&input), "9");
+        assert_eq!(puzzle.part_2(&input), "2 numbers");
+        assert!(!puzzle.visualize(&input, 1, &mut |_| {}));
+        assert!(puzzle.images(&input).is_empty());
+        assert_eq!(puzzle.generate(1, 10), None);
+    }
+
+    #[test]
+    fn test_alternatives() {
+        let puzzle: &dyn Puzzle = &Sum;
+        let input = puzzle.parse("4\n5").unwrap();
+
+        assert_eq!(puzzle.implementations(1), vec![REFERENCE, "backwards"]);
+        assert_eq!(puzzle.implementations(2), vec![REFERENCE]);
+        assert_eq!(
+            puzzle.solve_with(&input, 1, "backwards"),
+            Some("9".to_string())
+        );
+        assert_eq!(
+            puzzle.solve_with(&input, 2, REFERENCE),
+            Some("2 numbers".to_string())
+        );
+        assert_eq!(puzzle.solve_with(&input, 2, "backwards"), None);
+    }
+
+    #[test]
+    fn test_leading_blank_lines_are_counted() {
+        let data = crate::input::normalize("\r\n\r\n4\r\nsix\r\n", Normalize::Full);
+
+        assert_eq!(Sum.parse(&data).unwrap_err().line(), Some(4));
+        assert!(Sum.parse("\n\n4\n5\n").is_ok());
+    }
+
+    #[test]
+    fn test_parse_error_is_located() {
+        let error = Sum.parse("4\n5\nsix").unwrap_err();
+
+        assert_eq!((error.year(), error.day()), (Some(2022), Some(1)));
+        assert_eq!(error.line(), Some(3));
+        assert_eq!(error.column(), Some(1));
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+
+use crate::{grid::Grid, point::Point};
+
+/// A blank character picture to draw the state of a simulation on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Canvas {
+    grid: Grid<char>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            grid: Grid::filled(height, width, ' '),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.columns()
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.rows()
+    }
+
+    /// Draws `c` at `point`, anything outside of the canvas being cut off.
+    pub fn draw(&mut self, point: Point, c: char) {
+        if let Some(cell) = self.grid.get_mut(point) {
+            *cell = c;
+        }
+    }
+
+    /// Writes `text` from `point` to the right.
+    pub fn text(&mut self, point: Point, text: &str) {
+        for (i, c) in text.chars().enumerate() {
+            self.draw(point + Point::new(i as i64, 0), c);
+        }
+    }
+}
+
+impl fmt::Display for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            write!(
+                f,
+                "{}",
+                self.grid.row(y).iter().collect::<String>().trim_end()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// One step of a visualization: what happened and what it looks like afterwards.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub caption: String,
+    pub canvas: Canvas,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, canvas: Canvas) -> Self {
+        Self {
+            caption: caption.into(),
+            canvas,
+        }
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}\n\n{}", self.caption, self.canvas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canvas() {
+        let mut canvas = Canvas::new(4, 2);
+        canvas.text(Point::new(1, 0), "abcdef");
+        canvas.draw(Point::new(0, 1), '#');
+        canvas.draw(Point::new(-1, 1), '!');
+        canvas.draw(Point::new(0, 2), '!');
+
+        assert_eq!((canvas.width(), canvas.height()), (4, 2));
+        assert_eq!(canvas.to_string(), " abc\n#");
+        assert_eq!(
+            Frame::new("step 1", canvas).to_string(),
+            "step 1\n\n abc\n#"
+        );
+    }
+}
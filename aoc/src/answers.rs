@@ -0,0 +1,114 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::input;
+
+use crate::Error;
+
+/// The confirmed answers of a year for our own inputs, next to the inputs they belong to.
+pub fn default_path(year: u16) -> PathBuf {
+    input::year_dir(year).join("answers.json")
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    days: BTreeMap<u8, BTreeMap<u8, String>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let data = match fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(Error::Io(path.to_path_buf(), err)),
+        };
+
+        let days =
+            serde_json::from_str(&data).map_err(|err| Error::Invalid(path.to_path_buf(), err))?;
+
+        Ok(Self { days })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let to_error = |err| Error::Io(path.to_path_buf(), err);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(to_error)?;
+        }
+
+        let mut data = serde_json::to_string_pretty(&self.days).unwrap();
+        data.push('\n');
+
+        fs::write(path, data).map_err(to_error)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.days.get(&day)?.get(&part).map(String::as_str)
+    }
+
+    pub fn has_day(&self, day: u8) -> bool {
+        self.days.contains_key(&day)
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str) {
+        self.days
+            .entry(day)
+            .or_default()
+            .insert(part, answer.to_string());
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.record(7, 1, "95437");
+
+        assert_eq!(answers.check(7, 1, "95437"), Verdict::Correct);
+        assert_eq!(
+            answers.check(7, 1, "1"),
+            Verdict::Wrong {
+                expected: "95437".to_string()
+            }
+        );
+        assert_eq!(answers.check(7, 2, "1"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("inputs").join("answers.json");
+
+        assert_eq!(Answers::load(&path).unwrap(), Answers::default());
+
+        let mut answers = Answers::default();
+        answers.record(10, 2, "##..\n..##");
+        answers.record(1, 1, "24000");
+        answers.save(&path).unwrap();
+
+        assert_eq!(Answers::load(&path).unwrap(), answers);
+    }
+}
@@ -0,0 +1,262 @@
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{ParseError, Puzzle, REFERENCE};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part1 => write!(f, "part 1"),
+            Self::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Stats {
+    pub runs: usize,
+    #[serde(rename = "min_ns", serialize_with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", serialize_with = "nanos")]
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+
+        Self {
+            runs,
+            min: samples[0],
+            median,
+            max: samples[runs - 1],
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PhaseReport {
+    pub phase: Phase,
+    /// Which implementation of a part was timed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implementation: Option<&'static str>,
+    #[serde(flatten)]
+    pub stats: Stats,
+    /// Megabytes of input handled per second, based on the median.
+    pub throughput_mb_s: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub input_bytes: usize,
+    pub phases: Vec<PhaseReport>,
+}
+
+impl PhaseReport {
+    fn label(&self) -> String {
+        match self.implementation {
+            Some(name) if name != REFERENCE => format!("{} {}", self.phase, name),
+            _ => self.phase.to_string(),
+        }
+    }
+}
+
+impl DayReport {
+    /// How many times faster than the reference of its part an alternative is.
+    fn speedup(&self, report: &PhaseReport) -> Option<f64> {
+        if report.implementation.is_none_or(|name| name == REFERENCE) {
+            return None;
+        }
+
+        let reference = self
+            .phases
+            .iter()
+            .find(|r| r.phase == report.phase && r.implementation == Some(REFERENCE))?;
+
+        Some(reference.stats.median.as_secs_f64() / report.stats.median.as_secs_f64())
+    }
+}
+
+impl fmt::Display for DayReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Day {:02} ({} bytes)", self.day, self.input_bytes)?;
+
+        let width = self
+            .phases
+            .iter()
+            .map(|r| r.label().len())
+            .max()
+            .unwrap_or(0);
+
+        for report in &self.phases {
+            write!(
+                f,
+                "  {:<width$} min {:>10.1?}  median {:>10.1?}  max {:>10.1?}  {:>10.2} MB/s",
+                report.label(),
+                report.stats.min,
+                report.stats.median,
+                report.stats.max,
+                report.throughput_mb_s,
+                width = width
+            )?;
+
+            match self.speedup(report) {
+                Some(speedup) => writeln!(f, "  {:.2}x the reference", speedup)?,
+                None => writeln!(f)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Times every phase of a day `runs` times, after a warm-up run.
+pub fn bench(puzzle: &dyn Puzzle, data: &str, runs: usize) -> Result<DayReport, ParseError> {
+    let runs = runs.max(1);
+    let input = puzzle.parse(data)?;
+
+    let mut phases = vec![(
+        Phase::Parse,
+        None,
+        measure(runs, || drop(black_box(puzzle.parse(data)))),
+    )];
+
+    // every implementation of a part is timed, for them to be compared side by side
+    for (part, phase) in [(1, Phase::Part1), (2, Phase::Part2)] {
+        for name in puzzle.implementations(part) {
+            let stats = measure(runs, || {
+                drop(black_box(puzzle.solve_with(&input, part, name)));
+            });
+
+            phases.push((phase, Some(name), stats));
+        }
+    }
+
+    Ok(DayReport {
+        day: puzzle.day(),
+        input_bytes: data.len(),
+        phases: phases
+            .into_iter()
+            .map(|(phase, implementation, stats)| PhaseReport {
+                phase,
+                implementation,
+                throughput_mb_s: throughput(data.len(), stats.median),
+                stats,
+            })
+            .collect(),
+    })
+}
+
+fn measure(runs: usize, mut f: impl FnMut()) -> Stats {
+    f();
+
+    let samples = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::new(samples)
+}
+
+fn throughput(bytes: usize, duration: Duration) -> f64 {
+    if duration.is_zero() {
+        return f64::INFINITY;
+    }
+
+    bytes as f64 / 1_000_000.0 / duration.as_secs_f64()
+}
+
+pub(crate) fn nanos<S: serde::Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::new(vec![ms(5), ms(1), ms(3), ms(2)]);
+
+        assert_eq!(
+            stats,
+            Stats {
+                runs: 4,
+                min: ms(1),
+                median: Duration::from_micros(2500),
+                max: ms(5),
+            }
+        );
+    }
+
+    #[test]
+    fn test_bench_reports_every_phase() {
+        let report = bench(&aoc_2022_day_01::Day01, "1000\n2000\n\n4000", 3).unwrap();
+        let phases = report.phases.iter().map(|p| p.phase).collect::<Vec<_>>();
+
+        assert_eq!(report.day, 1);
+        assert_eq!(phases, vec![Phase::Parse, Phase::Part1, Phase::Part2]);
+        assert!(report.phases.iter().all(|p| p.stats.runs == 3));
+    }
+
+    #[test]
+    fn test_alternatives_side_by_side() {
+        let report = bench(&aoc_2022_day_06::Day06, "mjqjpqmgbljsphdztnvjfqwrcgsmlb", 1).unwrap();
+        let labels = report.phases.iter().map(|p| p.label()).collect::<Vec<_>>();
+
+        assert_eq!(
+            labels,
+            vec![
+                "parse",
+                "part 1",
+                "part 1 linear",
+                "part 2",
+                "part 2 linear"
+            ]
+        );
+        assert!(report.to_string().contains("the reference"));
+        assert_eq!(report.speedup(&report.phases[1]), None);
+    }
+
+    #[test]
+    fn test_json() {
+        let report = bench(&aoc_2022_day_01::Day01, "1000", 1).unwrap();
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["day"], 1);
+        assert_eq!(json["phases"][1]["phase"], "part1");
+        assert_eq!(json["phases"][1]["implementation"], "reference");
+        assert!(json["phases"][0].get("implementation").is_none());
+        assert!(json["phases"][0]["median_ns"].is_u64());
+    }
+}
@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use crate::{config::Config, Error};
+
+/// Asked for by the website from automated tools.
+const USER_AGENT: &str = "github.com/afroborg/advent-of-code-22";
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, Error> {
+        let session = config.session().ok_or(Error::MissingSession)?;
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Ok(Self {
+            agent,
+            base_url: config.base_url().to_string(),
+            session: session.to_string(),
+        })
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self.get(&url)?;
+
+        response
+            .into_string()
+            .map_err(|err| Error::Transport(url, err.to_string()))
+    }
+
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, Error> {
+        let url = format!("{}/{}/day/{}", self.base_url, year, day);
+        let response = self.get(&url)?;
+
+        response
+            .into_string()
+            .map_err(|err| Error::Transport(url, err.to_string()))
+    }
+
+    /// Sends an answer, returning the page the website answers with.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String, Error> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self
+            .request("POST", &url)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|err| Error::from_ureq(&url, err))?;
+
+        response
+            .into_string()
+            .map_err(|err| Error::Transport(url, err.to_string()))
+    }
+
+    fn get(&self, url: &str) -> Result<ureq::Response, Error> {
+        self.request("GET", url)
+            .call()
+            .map_err(|err| Error::from_ureq(url, err))
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
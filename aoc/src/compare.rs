@@ -0,0 +1,123 @@
+use std::fmt;
+
+use aoc_core::{ParseError, Puzzle, REFERENCE};
+
+/// An alternative implementation that does not give the answer of the reference.
+#[derive(Debug, PartialEq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub name: &'static str,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {:02} part {}: `{}` gave {} but the {} gave {}",
+            self.day, self.part, self.name, self.actual, REFERENCE, self.expected
+        )
+    }
+}
+
+/// Whether any part of the day has another implementation than the reference.
+pub fn has_alternatives(puzzle: &dyn Puzzle) -> bool {
+    [1, 2]
+        .into_iter()
+        .any(|part| puzzle.implementations(part).len() > 1)
+}
+
+/// Solves both parts with every implementation and reports the ones that
+/// disagree with the reference.
+pub fn compare(puzzle: &dyn Puzzle, data: &str) -> Result<Vec<Mismatch>, ParseError> {
+    let input = puzzle.parse(data)?;
+    let mut mismatches = vec![];
+
+    for part in [1, 2] {
+        let mut names = puzzle.implementations(part).into_iter();
+        let solve = |name| puzzle.solve_with(&input, part, name).unwrap();
+        let expected = names.next().map(solve).unwrap();
+
+        for name in names {
+            let actual = solve(name);
+
+            if actual != expected {
+                mismatches.push(Mismatch {
+                    day: puzzle.day(),
+                    part,
+                    name,
+                    expected: expected.clone(),
+                    actual,
+                });
+            }
+        }
+    }
+
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{Alternative, Solution};
+
+    struct Count;
+
+    impl Solution for Count {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 1;
+
+        type Input = Vec<u8>;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(data: &str) -> Result<Self::Input, ParseError> {
+            Ok(data.bytes().collect())
+        }
+
+        fn part_1(input: &Self::Input) -> Self::Part1 {
+            input.len()
+        }
+
+        fn part_2(input: &Self::Input) -> Self::Part2 {
+            input.iter().filter(|&&b| b == b'x').count()
+        }
+
+        fn alternatives() -> Vec<Alternative<Self::Input>> {
+            vec![
+                Alternative {
+                    name: "iter",
+                    part: 1,
+                    solve: |input| input.iter().map(|_| 1).sum::<usize>().to_string(),
+                },
+                Alternative {
+                    name: "off-by-one",
+                    part: 2,
+                    solve: |input| input.iter().filter(|&&b| b == b'x').count().to_string() + "1",
+                },
+            ]
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        assert!(has_alternatives(&Count));
+        assert!(!has_alternatives(&aoc_2022_day_01::Day01));
+        assert_eq!(
+            compare(&Count, "").unwrap(),
+            vec![Mismatch {
+                day: 1,
+                part: 2,
+                name: "off-by-one",
+                expected: "0".to_string(),
+                actual: "01".to_string(),
+            }]
+        );
+        assert_eq!(
+            compare(&Count, "").unwrap()[0].to_string(),
+            "day 01 part 2: `off-by-one` gave 01 but the reference gave 0"
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::Error;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Kept out of git, the session token logs in as one of us.
+pub fn default_path() -> PathBuf {
+    PathBuf::from("aoc.json")
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    /// Only changed to test against a local server.
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// The config file, overridden by `AOC_SESSION` and `AOC_BASE_URL`.
+    pub fn from_env() -> Result<Self, Error> {
+        Ok(Self::load(&default_path())?.with_vars(|name| env::var(name).ok()))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let data = match fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(Error::Io(path.to_path_buf(), err)),
+        };
+
+        serde_json::from_str(&data).map_err(|err| Error::Invalid(path.to_path_buf(), err))
+    }
+
+    pub fn with_vars(mut self, var: impl Fn(&str) -> Option<String>) -> Self {
+        let var = |name| var(name).filter(|value| !value.trim().is_empty());
+
+        if let Some(session) = var("AOC_SESSION") {
+            self.session = Some(session);
+        }
+
+        if let Some(base_url) = var("AOC_BASE_URL") {
+            self.base_url = Some(base_url);
+        }
+
+        self
+    }
+
+    pub fn session(&self) -> Option<&str> {
+        self.session
+            .as_deref()
+            .map(str::trim)
+            .filter(|session| !session.is_empty())
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url
+            .as_deref()
+            .unwrap_or(DEFAULT_BASE_URL)
+            .trim_end_matches('/')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("aoc.json");
+
+        assert_eq!(Config::load(&path).unwrap(), Config::default());
+
+        fs::write(&path, r#"{ "session": " 53616c74 \n" }"#).unwrap();
+        let config = Config::load(&path).unwrap();
+
+        assert_eq!(config.session(), Some("53616c74"));
+        assert_eq!(config.base_url(), DEFAULT_BASE_URL);
+
+        fs::write(&path, r#"{ "token": "53616c74" }"#).unwrap();
+        assert!(matches!(Config::load(&path), Err(Error::Invalid(..))));
+    }
+
+    #[test]
+    fn test_variables_override_the_file() {
+        let config = Config {
+            session: Some("from-file".to_string()),
+            base_url: None,
+        };
+        let config = config.with_vars(|name| match name {
+            "AOC_SESSION" => Some("from-env".to_string()),
+            "AOC_BASE_URL" => Some("http://127.0.0.1:8080/".to_string()),
+            _ => None,
+        });
+
+        assert_eq!(config.session(), Some("from-env"));
+        assert_eq!(config.base_url(), "http://127.0.0.1:8080");
+        assert_eq!(config.clone().with_vars(|_| Some(" ".to_string())), config);
+    }
+}
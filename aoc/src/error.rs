@@ -0,0 +1,74 @@
+use std::{fmt, io, path::PathBuf};
+
+use crate::submit::Refusal;
+
+/// An error of the commands that talk to the website or read and write files.
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, io::Error),
+    Invalid(PathBuf, serde_json::Error),
+    MissingSession,
+    Status { url: String, status: u16 },
+    Transport(String, String),
+    Refused(Refusal),
+    MissingDay(PathBuf),
+    NoDescription,
+}
+
+impl Error {
+    pub(crate) fn from_ureq(url: &str, err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(status, _) => Self::Status {
+                url: url.to_string(),
+                status,
+            },
+            ureq::Error::Transport(transport) => {
+                // the error itself repeats the url, only its kind and causes are kept
+                let mut message = transport.kind().to_string();
+                let causes =
+                    transport.message().map(str::to_string).into_iter().chain(
+                        std::error::Error::source(&transport).map(|source| source.to_string()),
+                    );
+
+                for cause in causes {
+                    message = format!("{}: {}", message, cause);
+                }
+
+                Self::Transport(url.to_string(), message)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, source) => write!(f, "unable to access {}: {}", path.display(), source),
+            Self::Invalid(path, source) => write!(f, "{} is not valid: {}", path.display(), source),
+            Self::MissingSession => write!(
+                f,
+                "no session token, set `session` in aoc.json or the AOC_SESSION variable"
+            ),
+            // the website answers a bad or expired session with an error instead of a 401
+            Self::Status { url, status } => {
+                write!(f, "{} answered {}", url, status)?;
+
+                match status {
+                    400 | 401 | 403 | 500 => write!(f, ", is the session token still valid?"),
+                    404 => write!(f, ", is the puzzle unlocked yet?"),
+                    _ => Ok(()),
+                }
+            }
+            Self::Transport(url, message) => write!(f, "unable to reach {}: {}", url, message),
+            Self::Refused(refusal) => write!(f, "not sent: {}", refusal),
+            Self::MissingDay(dir) => write!(
+                f,
+                "{} does not exist, create it with `aoc new` first",
+                dir.display()
+            ),
+            Self::NoDescription => write!(f, "the page has no puzzle description"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
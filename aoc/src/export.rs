@@ -0,0 +1,135 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use aoc_core::image::Image;
+
+use crate::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Ppm => "ppm",
+            Self::Png => "png",
+            Self::Svg => "svg",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ppm" => Ok(Self::Ppm),
+            "png" => Ok(Self::Png),
+            "svg" => Ok(Self::Svg),
+            _ => Err(format!("expected `ppm`, `png` or `svg`, got `{}`", s)),
+        }
+    }
+}
+
+pub fn encode(image: &Image, format: Format) -> io::Result<Vec<u8>> {
+    match format {
+        Format::Ppm => Ok(image.to_ppm()),
+        Format::Png => png(image),
+        Format::Svg => Ok(image.to_svg().into_bytes()),
+    }
+}
+
+/// Writes `image` as `<dir>/day-NN-<name>.<extension>`, returning the path of the file.
+pub fn save(
+    image: &Image,
+    dir: &Path,
+    day: u8,
+    name: &str,
+    format: Format,
+) -> Result<PathBuf, Error> {
+    let path = dir.join(format!("day-{:02}-{}.{}", day, name, format.extension()));
+    let to_error = |err| Error::Io(path.clone(), err);
+
+    let data = encode(image, format).map_err(to_error)?;
+
+    fs::create_dir_all(dir).map_err(to_error)?;
+    fs::write(&path, data).map_err(to_error)?;
+
+    Ok(path)
+}
+
+fn png(image: &Image) -> io::Result<Vec<u8>> {
+    if image.width() == 0 || image.height() == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "a PNG cannot be empty",
+        ));
+    }
+
+    let mut data = vec![];
+    let mut encoder = png::Encoder::new(&mut data, image.width() as u32, image.height() as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let pixels = image
+        .pixels()
+        .iter()
+        .flat_map(|pixel| [pixel.0, pixel.1, pixel.2])
+        .collect::<Vec<_>>();
+
+    // writing to memory cannot fail, and the size of a non-empty image always
+    // matches the header
+    let mut writer = encoder.write_header().unwrap();
+    writer.write_image_data(&pixels).unwrap();
+    writer.finish().unwrap();
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::image::Rgb;
+
+    #[test]
+    fn test_png_round_trip() {
+        let mut image = Image::new(3, 2, Rgb::BLACK);
+        image.set(aoc_core::point::Point::new(2, 1), Rgb(1, 2, 3));
+
+        let data = encode(&image, Format::Png).unwrap();
+        let mut reader = png::Decoder::new(data.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+
+        assert_eq!((info.width, info.height), (3, 2));
+        assert_eq!(&pixels[15..18], &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = Image::new(1, 1, Rgb::WHITE);
+        let path = save(&image, &dir.path().join("images"), 8, "trees", Format::Svg).unwrap();
+
+        assert!(path.ends_with("images/day-08-trees.svg"));
+        assert!(fs::read_to_string(path).unwrap().starts_with("<svg"));
+        assert_eq!("PNG".parse(), Ok(Format::Png));
+    }
+
+    #[test]
+    fn test_save_empty_png() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = Image::new(0, 0, Rgb::WHITE);
+        let error = save(&image, dir.path(), 8, "trees", Format::Png).unwrap_err();
+
+        assert!(error.to_string().ends_with("a PNG cannot be empty"));
+        assert!(!dir.path().join("day-08-trees.png").exists());
+    }
+}
@@ -0,0 +1,27 @@
+use std::{fs, path::Path};
+
+use crate::{client::Client, config::Config, Error};
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Downloaded,
+    Cached,
+}
+
+/// Downloads the input to `path`, unless it is there already.
+pub fn fetch(config: &Config, year: u16, day: u8, path: &Path) -> Result<Fetched, Error> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+
+    let data = Client::new(config)?.input(year, day)?;
+    let to_error = |err| Error::Io(path.to_path_buf(), err);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(to_error)?;
+    }
+
+    fs::write(path, data).map_err(to_error)?;
+
+    Ok(Fetched::Downloaded)
+}
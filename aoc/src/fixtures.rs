@@ -0,0 +1,99 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The expected answer `aoc new` writes until the real one is filled in.
+pub const PLACEHOLDER: &str = "TODO";
+
+/// An example input `<name>.txt` with the expected answers stored next to it
+/// in `<name>.part1` and `<name>.part2`. A part without a file is not checked.
+#[derive(Debug, PartialEq)]
+pub struct Fixture {
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: Vec<(u8, String)>,
+}
+
+/// The directory of the crate solving `day` of `year`.
+pub fn day_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day-{:02}", day))
+}
+
+pub fn fixtures_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    day_dir(root, year, day).join("fixtures")
+}
+
+/// Finds every fixture in `dir`, sorted by name. A missing directory has no fixtures.
+pub fn discover(dir: &Path) -> io::Result<Vec<Fixture>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err),
+    };
+
+    let mut inputs = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    inputs.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+    inputs.sort();
+
+    inputs
+        .into_iter()
+        .map(|input| {
+            let mut expected = vec![];
+
+            for part in [1, 2] {
+                match fs::read_to_string(input.with_extension(format!("part{}", part))) {
+                    Ok(answer) => expected.push((part, answer.trim_end_matches('\n').to_string())),
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                    Err(err) => return Err(err),
+                }
+            }
+
+            Ok(Fixture {
+                name: input.file_stem().unwrap().to_string_lossy().into_owned(),
+                input,
+                expected,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discover() {
+        let dir = tempfile::tempdir().unwrap();
+
+        fs::write(dir.path().join("example.txt"), "1\n2\n").unwrap();
+        fs::write(dir.path().join("example.part1"), "3\n").unwrap();
+        fs::write(dir.path().join("edge.txt"), "").unwrap();
+        fs::write(dir.path().join("edge.part2"), "##\n..\n").unwrap();
+        fs::write(dir.path().join("notes.md"), "").unwrap();
+
+        assert_eq!(
+            discover(dir.path()).unwrap(),
+            vec![
+                Fixture {
+                    name: "edge".to_string(),
+                    input: dir.path().join("edge.txt"),
+                    expected: vec![(2, "##\n..".to_string())],
+                },
+                Fixture {
+                    name: "example".to_string(),
+                    input: dir.path().join("example.txt"),
+                    expected: vec![(1, "3".to_string())],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_dir() {
+        assert_eq!(discover(Path::new("does/not/exist")).unwrap(), vec![]);
+    }
+}
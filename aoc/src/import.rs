@@ -0,0 +1,423 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{fixtures, Error};
+
+#[derive(Debug, PartialEq)]
+pub struct Page {
+    pub markdown: String,
+    /// Every `<pre><code>` block, most of them example inputs.
+    pub examples: Vec<String>,
+}
+
+/// Converts the `<article>` of every unlocked part to markdown.
+pub fn parse(html: &str) -> Page {
+    let mut writer = Writer::default();
+    let mut articles = 0;
+    let mut depth = 0_usize;
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open("article", _) => {
+                depth += 1;
+
+                if depth == 1 {
+                    articles += 1;
+                    writer.article = articles;
+                }
+            }
+            Token::Close("article") => depth = depth.saturating_sub(1),
+            token if depth > 0 => writer.token(token),
+            _ => {}
+        }
+    }
+
+    Page {
+        markdown: writer.out.trim_end().to_string() + "\n",
+        examples: writer.examples,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Imported {
+    pub description: PathBuf,
+    pub candidates: Vec<PathBuf>,
+}
+
+/// Writes `puzzle.md` and a `fixtures/candidate-N.txt` for every example not
+/// saved as a fixture yet.
+pub fn import(root: &Path, year: u16, day: u8, html: &str) -> Result<Imported, Error> {
+    let dir = fixtures::day_dir(root, year, day);
+
+    if !dir.is_dir() {
+        return Err(Error::MissingDay(dir));
+    }
+
+    let page = parse(html);
+
+    if page.markdown.trim().is_empty() {
+        return Err(Error::NoDescription);
+    }
+
+    let description = dir.join("puzzle.md");
+    write(&description, &page.markdown)?;
+
+    let fixtures_dir = fixtures::fixtures_dir(root, year, day);
+    let existing = fixtures::discover(&fixtures_dir)
+        .map_err(|err| Error::Io(fixtures_dir.clone(), err))?
+        .into_iter()
+        .filter(|fixture| !fixture.name.starts_with("candidate-"))
+        .map(|fixture| fs::read_to_string(&fixture.input).map_err(|err| (fixture.input, err)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|(path, err)| Error::Io(path, err))?;
+
+    let mut candidates = vec![];
+
+    for (i, example) in page.examples.iter().enumerate() {
+        if existing
+            .iter()
+            .any(|data| data.trim_end() == example.trim_end())
+        {
+            continue;
+        }
+
+        let path = fixtures_dir.join(format!("candidate-{}.txt", i + 1));
+        write(&path, example)?;
+        candidates.push(path);
+    }
+
+    Ok(Imported {
+        description,
+        candidates,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    let to_error = |err| Error::Io(path.to_path_buf(), err);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(to_error)?;
+    }
+
+    fs::write(path, contents).map_err(to_error)
+}
+
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    /// A tag name with its attributes as written.
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Splits the page into tags and text, dropping comments and doctypes.
+fn tokenize(html: &str) -> impl Iterator<Item = Token<'_>> {
+    let mut rest = html;
+
+    std::iter::from_fn(move || loop {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let Some(tag) = rest.strip_prefix('<') else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let (text, tail) = rest.split_at(end);
+            rest = tail;
+            return Some(Token::Text(text));
+        };
+
+        if let Some(comment) = tag.strip_prefix("!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let end = tag.find('>').unwrap_or(tag.len());
+        let inner = tag[..end].trim_end_matches('/');
+        rest = tag.get(end + 1..).unwrap_or("");
+
+        if inner.starts_with('!') || inner.starts_with('?') {
+            continue;
+        }
+
+        let (name, attributes) = inner.split_once(char::is_whitespace).unwrap_or((inner, ""));
+
+        return Some(match name.strip_prefix('/') {
+            Some(name) => Token::Close(name),
+            None => Token::Open(name, attributes),
+        });
+    })
+}
+
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let start = attributes.find(&format!("{}=\"", name))? + name.len() + 2;
+    let len = attributes[start..].find('"')?;
+
+    Some(&attributes[start..start + len])
+}
+
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| &rest[1..end]);
+        let character = entity.and_then(|entity| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                None => entity
+                    .strip_prefix('#')
+                    .and_then(|code| code.parse().ok())
+                    .and_then(char::from_u32),
+            },
+        });
+
+        match (entity, character) {
+            (Some(entity), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+#[derive(Default)]
+struct Writer {
+    out: String,
+    examples: Vec<String>,
+    /// The first article is headed by the title of the puzzle.
+    article: usize,
+    pre: Option<String>,
+    code: bool,
+    lists: usize,
+    links: Vec<String>,
+}
+
+impl Writer {
+    fn token(&mut self, token: Token) {
+        if let Some(pre) = &mut self.pre {
+            match token {
+                Token::Text(text) => pre.push_str(&decode(text)),
+                Token::Close("pre") => {
+                    let mut example = self.pre.take().unwrap();
+
+                    if !example.ends_with('\n') {
+                        example.push('\n');
+                    }
+
+                    self.out += &format!("```\n{}```\n\n", example);
+
+                    if !self.examples.contains(&example) {
+                        self.examples.push(example);
+                    }
+                }
+                _ => {}
+            }
+
+            return;
+        }
+
+        match token {
+            Token::Open("h2", _) => {
+                self.out += if self.article == 1 { "# " } else { "## " };
+            }
+            Token::Close("h2") => {
+                // the website frames its headings as `--- Day 1: Title ---`
+                let start = self.out.rfind("# ").map_or(0, |i| i + 2);
+                let heading = self.out[start..]
+                    .trim()
+                    .trim_start_matches("---")
+                    .trim_end_matches("---")
+                    .trim()
+                    .to_string();
+
+                self.out.truncate(start);
+                self.out += &heading;
+                self.out += "\n\n";
+            }
+            Token::Close("p") => {
+                self.out.truncate(self.out.trim_end_matches(' ').len());
+                self.out += "\n\n";
+            }
+            Token::Open("pre", _) => self.pre = Some(String::new()),
+            Token::Open("code", _) | Token::Close("code") => {
+                self.code = matches!(token, Token::Open(..));
+                self.out.push('`');
+            }
+            Token::Open("em", _) | Token::Close("em") if !self.code => self.out += "**",
+            Token::Open("ul", _) => {
+                // a nested list starts on the line after the item it is in
+                if self.lists > 0 && !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+
+                self.lists += 1;
+            }
+            Token::Close("ul") => {
+                self.lists = self.lists.saturating_sub(1);
+
+                if self.lists == 0 {
+                    self.out.push('\n');
+                }
+            }
+            Token::Open("li", _) => {
+                self.out += &"  ".repeat(self.lists.saturating_sub(1));
+                self.out += "- ";
+            }
+            Token::Close("li") if !self.out.ends_with('\n') => self.out.push('\n'),
+            Token::Open("a", attributes) => {
+                self.links
+                    .push(attribute(attributes, "href").unwrap_or("").to_string());
+                self.out.push('[');
+            }
+            Token::Close("a") => {
+                let href = self.links.pop().unwrap_or_default();
+                self.out += &format!("]({})", href);
+            }
+            Token::Open("br", _) => self.out.push('\n'),
+            Token::Text(text) => {
+                // the line breaks of the page are only layout outside of `<pre>`
+                let mut collapsed = String::new();
+
+                for c in decode(text).chars() {
+                    if !c.is_whitespace() {
+                        collapsed.push(c);
+                    } else if !collapsed.ends_with(' ') {
+                        collapsed.push(' ');
+                    }
+                }
+
+                if self.out.is_empty() || self.out.ends_with(['\n', ' ']) {
+                    self.out += collapsed.trim_start();
+                } else {
+                    self.out += &collapsed;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us"><head><title>Day 1 - Advent of Code 2022</title></head><body>
+<header><h1 class="title-global"><a href="/">Advent of Code</a></h1></header>
+<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <a href="https://en.wikipedia.org/wiki/Calorie">Calories</a> contained by the various meals.</p>
+<p>For example, suppose the Elves finished writing:</p>
+<pre><code>1000
+2000
+
+4000
+</code></pre>
+<ul>
+<li>The first Elf is carrying food with <code>1000</code> and <code>2000</code> Calories, a total of <code><em>3000</em></code> Calories.</li>
+<li>The second Elf is carrying <code>4000</code> &amp; nothing &lt;else&gt;.</li>
+</ul>
+<p>Find the Elf carrying the <em>most Calories</em>.</p>
+</article>
+<p>Your puzzle answer was <code>69912</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Same   example:</p>
+<pre><code>1000
+2000
+
+4000
+</code></pre>
+<!-- <p>A comment</p> -->
+<pre><code>a -&gt; b</code></pre>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_parse() {
+        let page = parse(PAGE);
+
+        assert_eq!(
+            page.markdown,
+            "# Day 1: Calorie Counting
+
+The Elves take turns writing down the number of [Calories](https://en.wikipedia.org/wiki/Calorie) contained by the various meals.
+
+For example, suppose the Elves finished writing:
+
+```
+1000
+2000
+
+4000
+```
+
+- The first Elf is carrying food with `1000` and `2000` Calories, a total of `3000` Calories.
+- The second Elf is carrying `4000` & nothing <else>.
+
+Find the Elf carrying the **most Calories**.
+
+## Part Two
+
+Same example:
+
+```
+1000
+2000
+
+4000
+```
+
+```
+a -> b
+```
+"
+        );
+        assert_eq!(page.examples, vec!["1000\n2000\n\n4000\n", "a -> b\n"]);
+    }
+
+    #[test]
+    fn test_import() {
+        let root = tempfile::tempdir().unwrap();
+        let fixtures = root.path().join("2022/day-01/fixtures");
+
+        assert!(matches!(
+            import(root.path(), 2022, 1, PAGE),
+            Err(Error::MissingDay(_))
+        ));
+
+        fs::create_dir_all(&fixtures).unwrap();
+        fs::write(fixtures.join("example.txt"), "1000\n2000\n\n4000\n").unwrap();
+
+        let imported = import(root.path(), 2022, 1, PAGE).unwrap();
+
+        assert_eq!(
+            imported.description,
+            root.path().join("2022/day-01/puzzle.md")
+        );
+        assert_eq!(imported.candidates, vec![fixtures.join("candidate-2.txt")]);
+        assert_eq!(
+            fs::read_to_string(fixtures.join("candidate-2.txt")).unwrap(),
+            "a -> b\n"
+        );
+
+        assert!(matches!(
+            import(root.path(), 2022, 1, "<html></html>"),
+            Err(Error::NoDescription)
+        ));
+    }
+}
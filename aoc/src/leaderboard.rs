@@ -0,0 +1,522 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt, fs,
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+
+use serde::{de, Deserialize, Deserializer};
+
+use crate::Error;
+
+/// A private leaderboard, from the export of its API link.
+#[derive(Debug, PartialEq)]
+pub struct Leaderboard {
+    pub year: u16,
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    /// In seconds since the epoch, by day and part.
+    pub stars: BTreeMap<(u8, u8), u64>,
+}
+
+#[derive(Deserialize)]
+struct Export {
+    #[serde(deserialize_with = "year")]
+    event: u16,
+    members: HashMap<String, ExportedMember>,
+}
+
+#[derive(Deserialize)]
+struct ExportedMember {
+    id: u64,
+    name: Option<String>,
+    completion_day_level: HashMap<u8, HashMap<u8, ExportedStar>>,
+}
+
+#[derive(Deserialize)]
+struct ExportedStar {
+    get_star_ts: u64,
+}
+
+fn year<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u16, D::Error> {
+    let event = String::deserialize(deserializer)?;
+
+    event
+        .parse()
+        .map_err(|_| de::Error::custom(format!("`{}` is not a year", event)))
+}
+
+impl Leaderboard {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let data = fs::read_to_string(path).map_err(|err| Error::Io(path.to_path_buf(), err))?;
+
+        Self::parse(&data).map_err(|err| Error::Invalid(path.to_path_buf(), err))
+    }
+
+    pub fn parse(data: &str) -> Result<Self, serde_json::Error> {
+        let export: Export = serde_json::from_str(data)?;
+        let mut members = export
+            .members
+            .into_values()
+            .map(|member| Member {
+                id: member.id,
+                // the website shows members without a public name like this
+                name: member
+                    .name
+                    .unwrap_or_else(|| format!("(anonymous user #{})", member.id)),
+                stars: member
+                    .completion_day_level
+                    .into_iter()
+                    .flat_map(|(day, parts)| {
+                        parts
+                            .into_iter()
+                            .map(move |(part, star)| ((day, part), star.get_star_ts))
+                    })
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+
+        members.sort_by_key(|member| member.id);
+
+        Ok(Self {
+            year: export.event,
+            members,
+        })
+    }
+
+    /// When the puzzle of `day` unlocked, at midnight in the US east coast.
+    pub fn unlocked_at(&self, day: u8) -> u64 {
+        let days = days_since_epoch(self.year.into(), 12, day.into());
+
+        (days * 24 + 5) as u64 * 3600
+    }
+
+    /// The times of the members with a star on `day`, fastest first.
+    pub fn times(&self, day: u8) -> Vec<DayTimes> {
+        let unlocked_at = self.unlocked_at(day);
+        let mut times = self
+            .members
+            .iter()
+            .filter_map(|member| {
+                let star = |part| {
+                    member
+                        .stars
+                        .get(&(day, part))
+                        .map(|&at| Duration::from_secs(at.saturating_sub(unlocked_at)))
+                };
+
+                Some(DayTimes {
+                    name: member.name.clone(),
+                    day,
+                    part_1: Some(star(1)?),
+                    part_2: star(2),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        times.sort_by_key(|times| (times.part_2.is_none(), times.part_2, times.part_1));
+        times
+    }
+
+    pub fn days(&self) -> Vec<u8> {
+        let mut days = self
+            .members
+            .iter()
+            .flat_map(|member| member.stars.keys().map(|&(day, _)| day))
+            .collect::<Vec<_>>();
+
+        days.sort();
+        days.dedup();
+        days
+    }
+
+    /// Ties go to whoever got their last star first, like on the website.
+    pub fn standings(&self, scoring: Scoring) -> Vec<Standing> {
+        let mut scores = vec![0; self.members.len()];
+        let points = self.members.len() as u64;
+
+        // every ranking of a star hands out as many points as there are members to
+        // the first, one less to the second and so on
+        let mut rank = |mut finishers: Vec<(u64, usize)>| {
+            finishers.sort();
+
+            for (place, (_, i)) in finishers.into_iter().enumerate() {
+                scores[i] += points - place as u64;
+            }
+        };
+
+        match scoring {
+            Scoring::Local => {
+                for day in self.days() {
+                    for part in [1, 2] {
+                        rank(
+                            self.members
+                                .iter()
+                                .enumerate()
+                                .filter_map(|(i, member)| {
+                                    Some((*member.stars.get(&(day, part))?, i))
+                                })
+                                .collect(),
+                        );
+                    }
+                }
+            }
+            Scoring::Delta => {
+                for day in self.days() {
+                    rank(
+                        self.members
+                            .iter()
+                            .enumerate()
+                            .filter_map(|(i, member)| {
+                                // a hand edited export may have the second star first
+                                let delta = member
+                                    .stars
+                                    .get(&(day, 2))?
+                                    .checked_sub(*member.stars.get(&(day, 1))?)?;
+
+                                Some((delta, i))
+                            })
+                            .collect(),
+                    );
+                }
+            }
+            Scoring::Stars => {
+                for (score, member) in scores.iter_mut().zip(&self.members) {
+                    *score = member.stars.len() as u64;
+                }
+            }
+        }
+
+        let mut standings = self
+            .members
+            .iter()
+            .zip(scores)
+            .map(|(member, score)| Standing {
+                name: member.name.clone(),
+                score,
+                stars: member.stars.len(),
+                last_star: member.stars.values().max().copied(),
+            })
+            .collect::<Vec<_>>();
+
+        standings.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then(a.last_star.is_none().cmp(&b.last_star.is_none()))
+                .then(a.last_star.cmp(&b.last_star))
+                .then(a.name.cmp(&b.name))
+        });
+        standings
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scoring {
+    /// The local score of the website.
+    Local,
+    /// Ranks the time between the two stars of every day.
+    Delta,
+    Stars,
+}
+
+impl FromStr for Scoring {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "local" => Ok(Self::Local),
+            "delta" => Ok(Self::Delta),
+            "stars" => Ok(Self::Stars),
+            _ => Err(format!("expected `local`, `delta` or `stars`, got `{}`", s)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Standing {
+    pub name: String,
+    pub score: u64,
+    pub stars: usize,
+    pub last_star: Option<u64>,
+}
+
+/// Counted from when the puzzle unlocked.
+#[derive(Debug, PartialEq)]
+pub struct DayTimes {
+    pub name: String,
+    pub day: u8,
+    pub part_1: Option<Duration>,
+    pub part_2: Option<Duration>,
+}
+
+impl DayTimes {
+    /// The time between the first and the second star, if they are in order.
+    pub fn delta(&self) -> Option<Duration> {
+        self.part_2?.checked_sub(self.part_1?)
+    }
+}
+
+pub struct Standings<'a>(pub &'a [Standing]);
+
+impl Standings<'_> {
+    pub fn csv(&self) -> String {
+        let mut csv = "rank,name,score,stars\n".to_string();
+
+        for (rank, standing) in self.0.iter().enumerate() {
+            csv += &format!(
+                "{},{},{},{}\n",
+                rank + 1,
+                csv_field(&standing.name),
+                standing.score,
+                standing.stars
+            );
+        }
+
+        csv
+    }
+}
+
+impl fmt::Display for Standings<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = name_width(self.0.iter().map(|standing| &standing.name));
+
+        write!(
+            f,
+            "Rank  {:<width$}  {:>6}  {:>5}",
+            "Name", "Score", "Stars"
+        )?;
+
+        for (rank, standing) in self.0.iter().enumerate() {
+            write!(
+                f,
+                "\n{:>4}  {:<width$}  {:>6}  {:>5}",
+                rank + 1,
+                standing.name,
+                standing.score,
+                standing.stars
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Times<'a>(pub &'a [DayTimes]);
+
+impl Times<'_> {
+    pub fn csv(&self) -> String {
+        let mut csv = "day,name,part_1,part_2,delta\n".to_string();
+        let seconds =
+            |time: Option<Duration>| time.map_or(String::new(), |t| t.as_secs().to_string());
+
+        for times in self.0 {
+            csv += &format!(
+                "{},{},{},{},{}\n",
+                times.day,
+                csv_field(&times.name),
+                seconds(times.part_1),
+                seconds(times.part_2),
+                seconds(times.delta())
+            );
+        }
+
+        csv
+    }
+}
+
+impl fmt::Display for Times<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = name_width(self.0.iter().map(|times| &times.name));
+
+        write!(
+            f,
+            "Day  {:<width$}  {:>10}  {:>10}  {:>10}",
+            "Name", "Part 1", "Part 2", "Delta"
+        )?;
+
+        for times in self.0 {
+            write!(
+                f,
+                "\n{:02}   {:<width$}  {:>10}  {:>10}  {:>10}",
+                times.day,
+                times.name,
+                clock(times.part_1),
+                clock(times.part_2),
+                clock(times.delta())
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn name_width<'a>(names: impl Iterator<Item = &'a String>) -> usize {
+    names
+        .map(|name| name.chars().count())
+        .chain([4])
+        .max()
+        .unwrap()
+}
+
+fn clock(time: Option<Duration>) -> String {
+    match time {
+        Some(time) => {
+            let seconds = time.as_secs();
+            format!(
+                "{}:{:02}:{:02}",
+                seconds / 3600,
+                seconds / 60 % 60,
+                seconds % 60
+            )
+        }
+        None => "-".to_string(),
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// The number of days from 1970-01-01 to a date of the proleptic Gregorian calendar.
+fn days_since_epoch(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Day 1 of 2022 unlocked at 1669870800.
+    const EXPORT: &str = r#"{
+        "owner_id": 1,
+        "event": "2022",
+        "members": {
+            "1": {
+                "id": 1, "name": "Ada", "stars": 3, "local_score": 9, "global_score": 0, "last_star_ts": 1669957500,
+                "completion_day_level": {
+                    "1": { "1": { "get_star_ts": 1669871100, "star_index": 0 }, "2": { "get_star_ts": 1669872000, "star_index": 1 } },
+                    "2": { "1": { "get_star_ts": 1669957500, "star_index": 2 } }
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 4, "local_score": 9, "global_score": 0, "last_star_ts": 1669965000,
+                "completion_day_level": {
+                    "1": { "1": { "get_star_ts": 1669899600, "star_index": 3 }, "2": { "get_star_ts": 1669899660, "star_index": 4 } },
+                    "2": { "1": { "get_star_ts": 1669964940, "star_index": 5 }, "2": { "get_star_ts": 1669965000, "star_index": 6 } }
+                }
+            },
+            "3": {
+                "id": 3, "name": "Grace, Hopper", "stars": 0, "local_score": 0, "global_score": 0, "last_star_ts": 0,
+                "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_unlocked_at() {
+        let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+
+        assert_eq!(leaderboard.unlocked_at(1), 1_669_870_800);
+        assert_eq!(leaderboard.unlocked_at(25), 1_669_870_800 + 24 * 86_400);
+        assert_eq!(days_since_epoch(1970, 1, 1), 0);
+        assert_eq!(days_since_epoch(2000, 3, 1), 11_017);
+    }
+
+    #[test]
+    fn test_times() {
+        let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+        let times = leaderboard.times(1);
+
+        assert_eq!(leaderboard.members[1].name, "(anonymous user #2)");
+        assert_eq!(times.len(), 2);
+        assert_eq!(times[0].name, "Ada");
+        assert_eq!(times[0].part_1, Some(Duration::from_secs(300)));
+        assert_eq!(times[0].delta(), Some(Duration::from_secs(900)));
+        assert_eq!(times[1].delta(), Some(Duration::from_secs(60)));
+
+        let times = leaderboard.times(2);
+
+        assert_eq!(times[0].name, "(anonymous user #2)");
+        assert_eq!(times[1].part_2, None);
+        assert_eq!(
+            Times(&times).to_string().lines().nth(2).unwrap(),
+            "02   Ada                     0:05:00           -           -"
+        );
+        assert_eq!(Times(&times).csv().lines().nth(2).unwrap(), "2,Ada,300,,");
+    }
+
+    #[test]
+    fn test_standings() {
+        let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+        let scores = |scoring| {
+            leaderboard
+                .standings(scoring)
+                .into_iter()
+                .map(|standing| (standing.name, standing.score))
+                .collect::<Vec<_>>()
+        };
+
+        // matches the local scores of the export
+        assert_eq!(
+            scores(Scoring::Local),
+            vec![
+                ("Ada".to_string(), 9),
+                ("(anonymous user #2)".to_string(), 9),
+                ("Grace, Hopper".to_string(), 0),
+            ]
+        );
+        assert_eq!(
+            scores(Scoring::Delta),
+            vec![
+                ("(anonymous user #2)".to_string(), 6),
+                ("Ada".to_string(), 2),
+                ("Grace, Hopper".to_string(), 0),
+            ]
+        );
+        assert_eq!(
+            scores(Scoring::Stars)[0],
+            ("(anonymous user #2)".to_string(), 4)
+        );
+
+        let standings = leaderboard.standings(Scoring::Local);
+
+        assert_eq!(
+            Standings(&standings).csv(),
+            "rank,name,score,stars\n1,Ada,9,3\n2,(anonymous user #2),9,4\n3,\"Grace, Hopper\",0,0\n"
+        );
+    }
+
+    #[test]
+    fn test_second_star_first() {
+        let export = EXPORT.replace("1669965000", "1669964000");
+        let leaderboard = Leaderboard::parse(&export).unwrap();
+        let times = leaderboard.times(2);
+
+        assert_eq!(times[0].name, "(anonymous user #2)");
+        assert_eq!(times[0].delta(), None);
+        assert_eq!(
+            leaderboard
+                .standings(Scoring::Delta)
+                .into_iter()
+                .map(|standing| standing.score)
+                .collect::<Vec<_>>(),
+            vec![3, 2, 0]
+        );
+    }
+}
@@ -0,0 +1,40 @@
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod compare;
+pub mod config;
+mod error;
+pub mod export;
+pub mod fetch;
+pub mod fixtures;
+pub mod import;
+pub mod leaderboard;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod visualize;
+
+use aoc_core::{ParseError, Puzzle};
+
+pub use error::Error;
+
+/// Parses the input once and solves the requested part, or both.
+pub fn solve(
+    puzzle: &dyn Puzzle,
+    data: &str,
+    part: Option<u8>,
+) -> Result<Vec<(u8, String)>, ParseError> {
+    let input = puzzle.parse(data)?;
+    let mut answers = vec![];
+
+    if part != Some(2) {
+        answers.push((1, puzzle.part_1(&input)));
+    }
+
+    if part != Some(1) {
+        answers.push((2, puzzle.part_2(&input)));
+    }
+
+    Ok(answers)
+}
@@ -0,0 +1,951 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc::{
+    answers::{self, Answers, Verdict},
+    bench,
+    client::Client,
+    compare,
+    config::Config,
+    export::{self, Format},
+    fetch::{self, Fetched},
+    import,
+    leaderboard::{Leaderboard, Scoring, Standings, Times},
+    registry, runner, scaffold, solve,
+    submit::{self, History, Outcome},
+    visualize::Player,
+};
+use aoc_core::{
+    input::{self, InputSource},
+    output, Puzzle,
+};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// The year of the puzzles, by default the latest one with a solved day
+    #[arg(short, long, global = true, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or `all` of them
+    Run {
+        day: DaySelection,
+
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the input from this file, or `-` for stdin, instead of `inputs/YYYY/day-NN.txt`
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<InputSource>,
+
+        /// Record the answers as the confirmed answers for our input
+        #[arg(long, conflicts_with = "input")]
+        record: bool,
+
+        /// Show the simulation step by step before the answers, for the days that support it
+        #[arg(long)]
+        visualize: bool,
+
+        /// Milliseconds between two frames of the visualization
+        #[arg(long, value_name = "MS", default_value_t = 100, requires = "visualize")]
+        delay: u64,
+
+        /// Write the frames of the visualization to this file instead of playing them
+        #[arg(long, value_name = "PATH", requires = "visualize")]
+        frames: Option<PathBuf>,
+
+        /// Print the answers, timings and failures as JSON
+        #[arg(long, conflicts_with = "visualize")]
+        json: bool,
+
+        /// Number of days to run at the same time, by default one per CPU
+        #[arg(short, long)]
+        jobs: Option<usize>,
+    },
+    /// Rerun the days with recorded answers and report any mismatch
+    Verify {
+        #[arg(default_value = "all")]
+        day: DaySelection,
+    },
+    /// Check that the alternative implementations of a day agree with the reference
+    Compare {
+        #[arg(default_value = "all")]
+        day: DaySelection,
+
+        /// Number of generated inputs to compare on
+        #[arg(long, default_value_t = 20)]
+        seeds: u64,
+
+        /// Size of the generated inputs
+        #[arg(short = 'n', long, default_value_t = 100)]
+        size: usize,
+
+        /// Also compare on this file, or `-` for stdin
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<InputSource>,
+    },
+    /// Time the parsing and both parts of a day, or of every day
+    Bench {
+        #[arg(default_value = "all")]
+        day: DaySelection,
+
+        /// Number of timed runs of every phase
+        #[arg(short, long, default_value_t = 25)]
+        runs: usize,
+
+        /// Read the input from this file, or `-` for stdin, instead of `inputs/YYYY/day-NN.txt`
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<InputSource>,
+
+        /// Print the statistics as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Export pictures of the input and answers of a day, or of every day that has some
+    Image {
+        #[arg(default_value = "all")]
+        day: DaySelection,
+
+        /// Image format: png, ppm or svg
+        #[arg(short, long, default_value = "png")]
+        format: Format,
+
+        /// Width in pixels of every cell
+        #[arg(short, long, default_value_t = 4)]
+        scale: usize,
+
+        /// Directory to write the images to
+        #[arg(short, long, value_name = "DIR", default_value = "images")]
+        out: PathBuf,
+
+        /// Read the input from this file, or `-` for stdin, instead of `inputs/YYYY/day-NN.txt`
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<InputSource>,
+    },
+    /// Print a random input for a day, the same one for the same seed and size
+    Generate {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+
+        /// Roughly the number of lines or records of the input
+        #[arg(short = 'n', long, default_value_t = 100)]
+        size: usize,
+
+        /// Write the input to this file instead of printing it
+        #[arg(short, long, value_name = "PATH")]
+        out: Option<PathBuf>,
+    },
+    /// Download the input of a day to `inputs/YYYY/day-NN.txt`, unless it is there already
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Send the answer of a part, by default the one solved from `inputs/YYYY/day-NN.txt`
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Send this answer instead of solving the part
+        answer: Option<String>,
+    },
+    /// Save the description of a day to `YYYY/day-NN/puzzle.md` and its examples as candidate fixtures
+    Import {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Read the puzzle page from this file instead of downloading it
+        #[arg(short, long, value_name = "PATH")]
+        file: Option<PathBuf>,
+    },
+    /// Rank the members of a private leaderboard from its JSON export, or show their star times
+    Leaderboard {
+        #[arg(value_name = "PATH")]
+        export: PathBuf,
+
+        /// How to score the members: local, delta or stars
+        #[arg(short, long, default_value = "local")]
+        scoring: Scoring,
+
+        /// Show the star times on this day, or `all` of them, instead of the ranking
+        #[arg(short, long)]
+        day: Option<DaySelection>,
+
+        /// Print CSV instead of a table
+        #[arg(long)]
+        csv: bool,
+    },
+    /// Create a new day from the template and register it
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+
+        match s.parse::<u8>() {
+            Ok(day) if (1..=25).contains(&day) => Ok(Self::Day(day)),
+            _ => Err(format!(
+                "expected a day between 1 and 25 or `all`, got `{}`",
+                s
+            )),
+        }
+    }
+}
+
+/// A day, or all of them, of a year.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Selection {
+    year: u16,
+    day: DaySelection,
+}
+
+impl Selection {
+    /// The registered puzzles to use and where to read their input from.
+    fn resolve(
+        self,
+        input: Option<InputSource>,
+    ) -> Result<(Vec<&'static dyn Puzzle>, InputSource), String> {
+        let year = self.year;
+
+        if self.day == DaySelection::All && input.is_some() {
+            return Err("--input can only be used with a single day".to_string());
+        }
+
+        let puzzles = match self.day {
+            DaySelection::All => match registry::days(year) {
+                days if days.is_empty() => {
+                    return Err(format!("no day of {} has been solved yet", year))
+                }
+                days => days,
+            },
+            DaySelection::Day(day) => vec![find(year, day)?],
+        };
+
+        Ok((puzzles, input.unwrap_or_default()))
+    }
+}
+
+/// The registered puzzle of a day.
+fn find(year: u16, day: u8) -> Result<&'static dyn Puzzle, String> {
+    registry::find(year, day)
+        .ok_or_else(|| format!("day {} of {} has not been solved yet", day, year))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let Some(year) = cli.year.or_else(registry::latest_year) else {
+        eprintln!("error: no day has been solved yet, pick a year with --year");
+        return ExitCode::FAILURE;
+    };
+
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            record,
+            visualize,
+            delay,
+            frames,
+            json,
+            jobs,
+        } => {
+            let playback = visualize.then(|| match frames {
+                Some(path) => Playback::File(path),
+                None => Playback::Terminal(Duration::from_millis(delay)),
+            });
+            let jobs = jobs.unwrap_or_else(|| {
+                thread::available_parallelism().map_or(1, |threads| threads.get())
+            });
+            let report = match json {
+                true => Report::Json,
+                false if day == DaySelection::All => Report::Table,
+                false => Report::Answers,
+            };
+
+            run_days(
+                Selection { year, day },
+                part,
+                input,
+                record,
+                playback,
+                report,
+                jobs,
+            )
+        }
+        Command::Verify { day } => verify_days(Selection { year, day }),
+        Command::Compare {
+            day,
+            seeds,
+            size,
+            input,
+        } => compare_days(Selection { year, day }, seeds, size, input),
+        Command::Bench {
+            day,
+            runs,
+            input,
+            json,
+        } => bench_days(Selection { year, day }, runs, input, json),
+        Command::Image {
+            day,
+            format,
+            scale,
+            out,
+            input,
+        } => export_images(Selection { year, day }, format, scale, &out, input),
+        Command::Generate {
+            day,
+            seed,
+            size,
+            out,
+        } => generate_input(year, day, seed, size, out.as_deref()),
+        Command::Fetch { day } => fetch_input(year, day),
+        Command::Submit { day, part, answer } => submit_answer(year, day, part, answer),
+        Command::Import { day, file } => import_puzzle(year, day, file.as_deref()),
+        Command::Leaderboard {
+            export,
+            scoring,
+            day,
+            csv,
+        } => show_leaderboard(&export, cli.year, scoring, day, csv),
+        Command::New { day } => scaffold::new_day(Path::new("."), year, day)
+            .map(|dir| println!("Created {}", dir.display()))
+            .map_err(|err| err.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Refuses to run with inputs or answers of `year` that are still where they were
+/// kept before the years were split, as they would be missed or saved twice.
+/// Only the commands that read or write them check it.
+fn check_legacy_inputs(year: u16) -> Result<(), String> {
+    if year != input::LEGACY_YEAR {
+        return Ok(());
+    }
+
+    let files = input::legacy_files(Path::new(input::INPUTS_DIR))
+        .map_err(|err| format!("unable to read {}: {}", input::INPUTS_DIR, err))?;
+
+    match files.first() {
+        None => Ok(()),
+        Some((from, to)) => Err(format!(
+            "{} file(s) in {} were saved before the years were split, move them to {} (such as {} to {})",
+            files.len(),
+            input::INPUTS_DIR,
+            input::year_dir(year).display(),
+            from.display(),
+            to.display()
+        )),
+    }
+}
+
+/// Where to show the frames of a visualization.
+enum Playback {
+    Terminal(Duration),
+    File(PathBuf),
+}
+
+/// How the runs of the days are printed.
+#[derive(PartialEq)]
+enum Report {
+    Answers,
+    Table,
+    Json,
+}
+
+fn run_days(
+    selection: Selection,
+    part: Option<u8>,
+    input: Option<InputSource>,
+    record: bool,
+    playback: Option<Playback>,
+    report: Report,
+    jobs: usize,
+) -> Result<(), String> {
+    if selection.day == DaySelection::All && playback.is_some() {
+        return Err("--visualize can only be used with a single day".to_string());
+    }
+
+    check_legacy_inputs(selection.year)?;
+
+    let (puzzles, source) = selection.resolve(input)?;
+    let answers_path = answers::default_path(selection.year);
+    let mut known = Answers::load(&answers_path).map_err(|err| err.to_string())?;
+
+    // the inputs are read up front, stdin can only be read once
+    let days = puzzles
+        .into_iter()
+        .map(|puzzle| {
+            let data = source
+                .load(puzzle)
+                .map_err(|err| format!("day {:02}: {}", puzzle.day(), err));
+
+            (puzzle, data)
+        })
+        .collect::<Vec<_>>();
+
+    if let (Some(playback), [(puzzle, Ok(data))]) = (&playback, days.as_slice()) {
+        visualize(*puzzle, data, part, playback)?;
+    }
+
+    let runs = runner::run_all(&days, part, jobs);
+
+    match report {
+        Report::Json => println!("{}", serde_json::to_string_pretty(&runs).unwrap()),
+        Report::Table => println!("{}", runner::Summary(&runs)),
+        Report::Answers => {
+            for run in &runs {
+                if let Some(err) = &run.error {
+                    eprintln!("error: {}", err);
+                    continue;
+                }
+
+                println!("Day {:02}", run.day);
+
+                for part in &run.parts {
+                    output::print_answer(part.part, &part.answer);
+                }
+            }
+        }
+    }
+
+    if record {
+        for run in &runs {
+            for part in &run.parts {
+                known.record(run.day, part.part, &part.answer);
+            }
+        }
+
+        known.save(&answers_path).map_err(|err| err.to_string())?;
+
+        if report != Report::Json {
+            println!("Recorded the answers in {}", answers_path.display());
+        }
+    }
+
+    match runs.iter().filter(|run| run.error.is_some()).count() {
+        0 => Ok(()),
+        failed => Err(format!("{} day(s) could not be run", failed)),
+    }
+}
+
+/// Plays the visualization of the requested part, or of both, one after the other.
+fn visualize(
+    puzzle: &dyn Puzzle,
+    data: &str,
+    part: Option<u8>,
+    playback: &Playback,
+) -> Result<(), String> {
+    let input = puzzle.parse(data).map_err(|err| err.to_string())?;
+    let mut player = match playback {
+        Playback::Terminal(delay) => Player::terminal(*delay),
+        Playback::File(path) => Player::file(path)
+            .map_err(|err| format!("unable to write {}: {}", path.display(), err))?,
+    };
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    for &part in &parts {
+        let shown = puzzle.visualize(&input, part, &mut |frame| player.show(frame));
+
+        // with both parts, the one without a visualization is skipped
+        if !shown && parts.len() == 1 {
+            return Err(format!(
+                "day {} part {} has no visualization",
+                puzzle.day(),
+                part
+            ));
+        }
+    }
+
+    let frames = player
+        .finish()
+        .map_err(|err| format!("unable to show the visualization: {}", err))?;
+
+    if frames == 0 {
+        return Err(format!("day {} has no visualization", puzzle.day()));
+    }
+
+    if let Playback::File(path) = playback {
+        println!("Wrote {} frames to {}", frames, path.display());
+    }
+
+    Ok(())
+}
+
+fn verify_days(selection: Selection) -> Result<(), String> {
+    check_legacy_inputs(selection.year)?;
+
+    let (puzzles, source) = selection.resolve(None)?;
+    let known =
+        Answers::load(&answers::default_path(selection.year)).map_err(|err| err.to_string())?;
+    let mut failed = 0;
+
+    for puzzle in puzzles {
+        if !known.has_day(puzzle.day()) {
+            println!("Day {:02}: no recorded answers", puzzle.day());
+            continue;
+        }
+
+        let data = match source.load(puzzle) {
+            Ok(data) => data,
+            Err(err) => {
+                eprintln!("error: day {:02}: {}", puzzle.day(), err);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let answers = match solve(puzzle, &data, None) {
+            Ok(answers) => answers,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                failed += 1;
+                continue;
+            }
+        };
+
+        for (part, answer) in answers {
+            let label = format!("Day {:02} part {}", puzzle.day(), part);
+
+            match known.check(puzzle.day(), part, &answer) {
+                Verdict::Correct => println!("{}: ok", label),
+                Verdict::Unknown => println!("{}: no recorded answer", label),
+                Verdict::Wrong { expected } => {
+                    println!(
+                        "{}: MISMATCH, expected {} but got {}",
+                        label, expected, answer
+                    );
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} answer(s) did not verify", failed)),
+    }
+}
+
+fn compare_days(
+    selection: Selection,
+    seeds: u64,
+    size: usize,
+    input: Option<InputSource>,
+) -> Result<(), String> {
+    let given = input.is_some();
+    let (puzzles, source) = selection.resolve(input)?;
+    let mut failed = 0;
+
+    for puzzle in puzzles {
+        if !compare::has_alternatives(puzzle) {
+            println!("Day {:02}: no alternative implementations", puzzle.day());
+            continue;
+        }
+
+        let mut inputs = vec![];
+
+        if given {
+            let data = source.load(puzzle).map_err(|err| err.to_string())?;
+            inputs.push(("the given input".to_string(), data));
+        }
+
+        for seed in 0..seeds {
+            if let Some(data) = puzzle.generate(seed, size) {
+                inputs.push((format!("seed {}", seed), data));
+            }
+        }
+
+        let mut mismatches = 0;
+
+        for (origin, data) in &inputs {
+            match compare::compare(puzzle, data) {
+                Ok(found) => {
+                    for mismatch in &found {
+                        println!("MISMATCH on {}: {}", origin, mismatch);
+                    }
+
+                    mismatches += found.len();
+                }
+                Err(err) => {
+                    eprintln!("error on {}:\n{}", origin, err);
+                    mismatches += 1;
+                }
+            }
+        }
+
+        if mismatches == 0 {
+            println!(
+                "Day {:02}: every implementation agrees on {} input(s)",
+                puzzle.day(),
+                inputs.len()
+            );
+        }
+
+        failed += mismatches;
+    }
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} comparison(s) did not agree", failed)),
+    }
+}
+
+fn bench_days(
+    selection: Selection,
+    runs: usize,
+    input: Option<InputSource>,
+    json: bool,
+) -> Result<(), String> {
+    check_legacy_inputs(selection.year)?;
+
+    let (puzzles, source) = selection.resolve(input)?;
+    let mut failed = 0;
+    let mut reports = vec![];
+
+    for puzzle in puzzles {
+        let data = match source.load(puzzle) {
+            Ok(data) => data,
+            Err(err) => {
+                eprintln!("error: day {:02}: {}", puzzle.day(), err);
+                failed += 1;
+                continue;
+            }
+        };
+
+        match bench::bench(puzzle, &data, runs) {
+            Ok(report) => {
+                if !json {
+                    println!("{}", report);
+                }
+
+                reports.push(report);
+            }
+            Err(err) => {
+                eprintln!("error: {}", err);
+                failed += 1;
+            }
+        }
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&reports).unwrap());
+    }
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} day(s) could not be benchmarked", failed)),
+    }
+}
+
+fn export_images(
+    selection: Selection,
+    format: Format,
+    scale: usize,
+    out: &Path,
+    input: Option<InputSource>,
+) -> Result<(), String> {
+    check_legacy_inputs(selection.year)?;
+
+    let (puzzles, source) = selection.resolve(input)?;
+    let out = out.join(selection.year.to_string());
+    let mut failed = 0;
+    let mut exported = 0;
+
+    'days: for puzzle in puzzles {
+        let data = match source.load(puzzle) {
+            Ok(data) => data,
+            Err(err) => {
+                eprintln!("error: day {:02}: {}", puzzle.day(), err);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let input = match puzzle.parse(&data) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                failed += 1;
+                continue;
+            }
+        };
+
+        for (name, image) in puzzle.images(&input) {
+            let path = match export::save(&image.scaled(scale), &out, puzzle.day(), name, format) {
+                Ok(path) => path,
+                Err(err) => {
+                    eprintln!("error: day {:02}: {}", puzzle.day(), err);
+                    failed += 1;
+                    continue 'days;
+                }
+            };
+
+            println!("Wrote {}", path.display());
+            exported += 1;
+        }
+    }
+
+    if exported == 0 && failed == 0 {
+        return Err("no images to export for these days".to_string());
+    }
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} day(s) could not be exported", failed)),
+    }
+}
+
+fn fetch_input(year: u16, day: u8) -> Result<(), String> {
+    check_legacy_inputs(year)?;
+
+    let config = Config::from_env().map_err(|err| err.to_string())?;
+    let path = input::default_path(year, day);
+
+    match fetch::fetch(&config, year, day, &path).map_err(|err| err.to_string())? {
+        Fetched::Downloaded => println!("Downloaded {}", path.display()),
+        Fetched::Cached => println!("{} is already downloaded", path.display()),
+    }
+
+    Ok(())
+}
+
+fn submit_answer(year: u16, day: u8, part: u8, answer: Option<String>) -> Result<(), String> {
+    check_legacy_inputs(year)?;
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let puzzle = find(year, day)?;
+            let data = InputSource::Default
+                .load(puzzle)
+                .map_err(|err| err.to_string())?;
+
+            solve(puzzle, &data, Some(part))
+                .map_err(|err| err.to_string())?
+                .remove(0)
+                .1
+        }
+    };
+
+    let config = Config::from_env().map_err(|err| err.to_string())?;
+    let client = Client::new(&config).map_err(|err| err.to_string())?;
+    let history_path = submit::default_path(year);
+    let mut history = History::load(&history_path).map_err(|err| err.to_string())?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+
+    let response = submit::submit(&client, &mut history, year, day, part, &answer, now)
+        .map_err(|err| err.to_string())?;
+    history.save(&history_path).map_err(|err| err.to_string())?;
+
+    let mut verdict = format!(
+        "Day {:02} part {}: {} is {}",
+        day, part, answer, response.outcome
+    );
+
+    if let Some(wait) = response.wait {
+        verdict += &format!(", the next answer can be sent in {}s", wait.as_secs());
+    }
+
+    if response.outcome != Outcome::Correct {
+        return Err(verdict);
+    }
+
+    println!("{}", verdict);
+
+    let answers_path = answers::default_path(year);
+    let mut known = Answers::load(&answers_path).map_err(|err| err.to_string())?;
+    known.record(day, part, &answer);
+    known.save(&answers_path).map_err(|err| err.to_string())
+}
+
+fn import_puzzle(year: u16, day: u8, file: Option<&Path>) -> Result<(), String> {
+    let html = match file {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|err| format!("unable to read {}: {}", path.display(), err))?,
+        None => {
+            let config = Config::from_env().map_err(|err| err.to_string())?;
+            let client = Client::new(&config).map_err(|err| err.to_string())?;
+
+            client.puzzle(year, day).map_err(|err| err.to_string())?
+        }
+    };
+
+    let imported =
+        import::import(Path::new("."), year, day, &html).map_err(|err| err.to_string())?;
+    println!("Wrote {}", imported.description.display());
+
+    for candidate in &imported.candidates {
+        println!("Wrote {}", candidate.display());
+    }
+
+    if !imported.candidates.is_empty() {
+        println!("Rename the examples to keep and add their answers in `<name>.part1` and `<name>.part2`");
+    }
+
+    Ok(())
+}
+
+fn show_leaderboard(
+    path: &Path,
+    year: Option<u16>,
+    scoring: Scoring,
+    day: Option<DaySelection>,
+    csv: bool,
+) -> Result<(), String> {
+    let leaderboard = Leaderboard::load(path).map_err(|err| err.to_string())?;
+
+    // an export knows its own year, so it only has to match an explicit --year
+    if let Some(year) = year.filter(|&year| year != leaderboard.year) {
+        return Err(format!(
+            "{} is the leaderboard of {}, not {}",
+            path.display(),
+            leaderboard.year,
+            year
+        ));
+    }
+
+    let Some(day) = day else {
+        let standings = leaderboard.standings(scoring);
+
+        if csv {
+            print!("{}", Standings(&standings).csv());
+        } else {
+            println!("{}", Standings(&standings));
+        }
+
+        return Ok(());
+    };
+
+    let days = match day {
+        DaySelection::All => leaderboard.days(),
+        DaySelection::Day(day) => vec![day],
+    };
+    let times = days
+        .into_iter()
+        .flat_map(|day| leaderboard.times(day))
+        .collect::<Vec<_>>();
+
+    if csv {
+        print!("{}", Times(&times).csv());
+    } else {
+        println!("{}", Times(&times));
+    }
+
+    Ok(())
+}
+
+fn generate_input(
+    year: u16,
+    day: u8,
+    seed: u64,
+    size: usize,
+    out: Option<&Path>,
+) -> Result<(), String> {
+    let puzzle = find(year, day)?;
+    let data = puzzle
+        .generate(seed, size)
+        .ok_or_else(|| format!("day {} has no input generator", day))?;
+
+    match out {
+        Some(path) => fs::write(path, data)
+            .map_err(|err| format!("unable to write {}: {}", path.display(), err)),
+        None => {
+            print!("{}", data);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_selection() {
+        assert_eq!("all".parse(), Ok(DaySelection::All));
+        assert_eq!("7".parse(), Ok(DaySelection::Day(7)));
+        assert!("26".parse::<DaySelection>().is_err());
+        assert!("seven".parse::<DaySelection>().is_err());
+    }
+
+    #[test]
+    fn test_cli() {
+        let cli = Cli::try_parse_from(["aoc", "run", "7", "--part", "2"]).unwrap();
+
+        assert!(matches!(
+            cli.command,
+            Command::Run {
+                day: DaySelection::Day(7),
+                part: Some(2),
+                input: None,
+                record: false,
+                visualize: false,
+                ..
+            }
+        ));
+        assert!(Cli::try_parse_from(["aoc", "run", "7", "--part", "3"]).is_err());
+
+        let cli = Cli::try_parse_from(["aoc", "run", "7", "--input", "-"]).unwrap();
+
+        assert!(matches!(
+            cli.command,
+            Command::Run {
+                input: Some(InputSource::Stdin),
+                ..
+            }
+        ));
+        assert_eq!(cli.year, None);
+
+        let cli = Cli::try_parse_from(["aoc", "verify", "--year", "2021"]).unwrap();
+
+        assert_eq!(cli.year, Some(2021));
+        assert!(Cli::try_parse_from(["aoc", "new", "1", "-y", "2014"]).is_err());
+    }
+}
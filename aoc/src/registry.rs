@@ -0,0 +1,80 @@
+use aoc_core::Puzzle;
+
+/// Every solved day of every year, in calendar order.
+pub static DAYS: &[&dyn Puzzle] = &[
+    &aoc_2022_day_01::Day01,
+    &aoc_2022_day_02::Day02,
+    &aoc_2022_day_03::Day03,
+    &aoc_2022_day_04::Day04,
+    &aoc_2022_day_05::Day05,
+    &aoc_2022_day_06::Day06,
+    &aoc_2022_day_07::Day07,
+    &aoc_2022_day_08::Day08,
+    &aoc_2022_day_09::Day09,
+    &aoc_2022_day_10::Day10,
+    &aoc_2022_day_11::Day11,
+    &aoc_2022_day_12::Day12,
+    &aoc_2022_day_13::Day13,
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static dyn Puzzle> {
+    DAYS.iter()
+        .copied()
+        .find(|puzzle| (puzzle.year(), puzzle.day()) == (year, day))
+}
+
+/// The solved days of `year`, in calendar order.
+pub fn days(year: u16) -> Vec<&'static dyn Puzzle> {
+    DAYS.iter()
+        .copied()
+        .filter(|puzzle| puzzle.year() == year)
+        .collect()
+}
+
+/// The most recent year with a solved day, the one commands default to.
+pub fn latest_year() -> Option<u16> {
+    DAYS.iter().map(|puzzle| puzzle.year()).max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_in_order() {
+        let days = DAYS
+            .iter()
+            .map(|puzzle| (puzzle.year(), puzzle.day()))
+            .collect::<Vec<_>>();
+
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(2022, 7).map(|puzzle| puzzle.day()), Some(7));
+        assert!(find(2022, 25).is_none());
+        assert!(find(2015, 7).is_none());
+        assert_eq!(days(2022).len(), DAYS.len());
+        assert_eq!(latest_year(), Some(2022));
+    }
+
+    #[test]
+    fn test_generated_inputs_solve_alike() {
+        for puzzle in DAYS {
+            for (seed, size) in [(0, 1), (1, 10), (2, 50)] {
+                // a freshly created day has no generator yet
+                let Some(data) = puzzle.generate(seed, size) else {
+                    continue;
+                };
+                // solving with every implementation also checks that they agree
+                let mismatches = crate::compare::compare(*puzzle, &data)
+                    .unwrap_or_else(|err| panic!("{}\n{}", err, data));
+
+                assert_eq!(mismatches, vec![], "seed {}", seed);
+
+                assert_eq!(puzzle.generate(seed, size), Some(data));
+            }
+        }
+    }
+}
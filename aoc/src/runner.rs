@@ -0,0 +1,356 @@
+use std::{
+    any::Any,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::Puzzle;
+use serde::Serialize;
+
+use crate::bench::nanos;
+
+/// The answers of a day with how long they took, or why there are none.
+#[derive(Debug, Serialize)]
+pub struct DayRun {
+    pub day: u8,
+    #[serde(rename = "parse_ns", serialize_with = "optional_nanos")]
+    pub parse: Option<Duration>,
+    pub parts: Vec<PartRun>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: String,
+    #[serde(rename = "time_ns", serialize_with = "nanos")]
+    pub time: Duration,
+}
+
+impl DayRun {
+    fn failed(day: u8, error: String) -> Self {
+        Self {
+            day,
+            parse: None,
+            parts: vec![],
+            error: Some(error),
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse.unwrap_or_default() + self.parts.iter().map(|p| p.time).sum::<Duration>()
+    }
+
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        self.parts
+            .iter()
+            .find(|p| p.part == part)
+            .map(|p| p.answer.as_str())
+    }
+}
+
+/// Parses the input and solves the requested part, or both, timing every step.
+/// A panic of the solution is reported like a parse error.
+pub fn run(puzzle: &dyn Puzzle, data: &str, part: Option<u8>) -> DayRun {
+    let run = || {
+        let start = Instant::now();
+        let input = match puzzle.parse(data) {
+            Ok(input) => input,
+            Err(err) => return DayRun::failed(puzzle.day(), err.to_string()),
+        };
+        let parse = start.elapsed();
+
+        let parts = [1, 2]
+            .into_iter()
+            .filter(|&p| part.is_none_or(|part| part == p))
+            .map(|part| {
+                let start = Instant::now();
+                let answer = match part {
+                    1 => puzzle.part_1(&input),
+                    _ => puzzle.part_2(&input),
+                };
+
+                PartRun {
+                    part,
+                    answer,
+                    time: start.elapsed(),
+                }
+            })
+            .collect();
+
+        DayRun {
+            day: puzzle.day(),
+            parse: Some(parse),
+            parts,
+            error: None,
+        }
+    };
+
+    panic::catch_unwind(AssertUnwindSafe(run)).unwrap_or_else(|payload| {
+        DayRun::failed(
+            puzzle.day(),
+            format!(
+                "day {:02} panicked: {}",
+                puzzle.day(),
+                panic_message(&*payload)
+            ),
+        )
+    })
+}
+
+/// Runs every day with its loaded input on up to `threads` threads at once,
+/// returning the runs in the order of the days.
+pub fn run_all(
+    days: &[(&dyn Puzzle, Result<String, String>)],
+    part: Option<u8>,
+    threads: usize,
+) -> Vec<DayRun> {
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(days.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, days.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some((puzzle, data)) = days.get(i) else {
+                    break;
+                };
+
+                let run = match data {
+                    Ok(data) => run(*puzzle, data, part),
+                    Err(err) => DayRun::failed(puzzle.day(), err.clone()),
+                };
+
+                runs.lock().unwrap().push((i, run));
+            });
+        }
+    });
+
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|&(i, _)| i);
+    runs.into_iter().map(|(_, run)| run).collect()
+}
+
+/// A table of the answers and timings of every day, with the totals below it.
+/// Answers spanning several lines are printed in full after the table.
+pub struct Summary<'a>(pub &'a [DayRun]);
+
+impl fmt::Display for Summary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let runs = self.0;
+        let cell = |run: &DayRun, part| match run.answer(part) {
+            Some(answer) if answer.contains('\n') => "(see below)".to_string(),
+            Some(answer) => answer.to_string(),
+            None => "-".to_string(),
+        };
+        let width = |part| {
+            runs.iter()
+                .map(|run| cell(run, part).len())
+                .chain([6])
+                .max()
+                .unwrap()
+        };
+        let (one, two) = (width(1), width(2));
+
+        writeln!(
+            f,
+            "Day  {:<one$}  {:<two$}  {:>10}  {:>10}  {:>10}  {:>10}",
+            "Part 1", "Part 2", "parse", "part 1", "part 2", "total"
+        )?;
+
+        for run in runs {
+            if let Some(error) = &run.error {
+                writeln!(
+                    f,
+                    "{:02}   error: {}",
+                    run.day,
+                    error.lines().next().unwrap_or("")
+                )?;
+                continue;
+            }
+
+            let time = |part| {
+                run.parts
+                    .iter()
+                    .find(|p| p.part == part)
+                    .map_or("-".to_string(), |p| format!("{:.1?}", p.time))
+            };
+
+            writeln!(
+                f,
+                "{:02}   {:<one$}  {:<two$}  {:>10}  {:>10}  {:>10}  {:>10}",
+                run.day,
+                cell(run, 1),
+                cell(run, 2),
+                format!("{:.1?}", run.parse.unwrap_or_default()),
+                time(1),
+                time(2),
+                format!("{:.1?}", run.total())
+            )?;
+        }
+
+        let failed = runs.iter().filter(|run| run.error.is_some()).count();
+        let total = runs.iter().map(DayRun::total).sum::<Duration>();
+
+        write!(
+            f,
+            "\n{} day(s), {} failed, {:.1?} in total",
+            runs.len(),
+            failed,
+            total
+        )?;
+
+        for run in runs {
+            for part in &run.parts {
+                if part.answer.contains('\n') {
+                    write!(
+                        f,
+                        "\n\nDay {:02} part {}:\n{}",
+                        run.day, part.part, part.answer
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload
+            .downcast_ref::<String>()
+            .map_or("unknown panic", String::as_str),
+    }
+}
+
+fn optional_nanos<S: serde::Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match duration {
+        Some(duration) => nanos(duration, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{ParseError, Solution};
+
+    #[test]
+    fn test_run_all() {
+        let days: Vec<(&dyn Puzzle, Result<String, String>)> = vec![
+            (
+                &aoc_2022_day_01::Day01,
+                Ok("1000\n2000\n\n4000\n".to_string()),
+            ),
+            (&aoc_2022_day_02::Day02, Err("no input".to_string())),
+            (&aoc_2022_day_04::Day04, Ok("2-4,x\n".to_string())),
+            (&aoc_2022_day_06::Day06, Ok("abcdefghijklmn\n".to_string())),
+        ];
+        let runs = run_all(&days, None, 3);
+        let days = runs.iter().map(|run| run.day).collect::<Vec<_>>();
+
+        assert_eq!(days, vec![1, 2, 4, 6]);
+        assert_eq!(runs[0].answer(1), Some("4000"));
+        assert_eq!(runs[0].answer(2), Some("7000"));
+        assert_eq!(runs[1].error.as_deref(), Some("no input"));
+        assert!(runs[2].error.as_ref().unwrap().contains("line 1, column 5"));
+        assert_eq!(runs[3].answer(2), Some("14"));
+    }
+
+    #[test]
+    fn test_panics_are_failures() {
+        struct Unsolved;
+
+        impl Solution for Unsolved {
+            const YEAR: u16 = 2022;
+            const DAY: u8 = 25;
+
+            type Input = ();
+            type Part1 = u32;
+            type Part2 = u32;
+
+            fn parse(_data: &str) -> Result<Self::Input, ParseError> {
+                Ok(())
+            }
+
+            fn part_1(_input: &Self::Input) -> Self::Part1 {
+                unimplemented!()
+            }
+
+            fn part_2(_input: &Self::Input) -> Self::Part2 {
+                unimplemented!()
+            }
+        }
+
+        let run = run(&Unsolved, "\n", Some(1));
+
+        assert!(run.error.unwrap().starts_with("day 25 panicked"));
+    }
+
+    #[test]
+    fn test_impossible_inputs_are_parse_errors() {
+        let days: Vec<(&dyn Puzzle, Result<String, String>)> = vec![
+            (&aoc_2022_day_03::Day03, Ok("abcd\n".to_string())),
+            (
+                &aoc_2022_day_05::Day05,
+                Ok("[A]\n 1   2 \n\nmove 5 from 1 to 2\n".to_string()),
+            ),
+            (&aoc_2022_day_10::Day10, Ok("noop\naddx 3\n".to_string())),
+            (
+                &aoc_2022_day_11::Day11,
+                Ok(concat!(
+                    "Monkey 0:\n",
+                    "  Starting items: 79\n",
+                    "  Operation: new = old * 19\n",
+                    "  Test: divisible by 23\n",
+                    "    If true: throw to monkey 7\n",
+                    "    If false: throw to monkey 0\n",
+                )
+                .to_string()),
+            ),
+        ];
+        let runs = run_all(&days, None, 2);
+        let errors = runs
+            .iter()
+            .map(|run| run.error.as_deref().unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(errors[0].starts_with("2022 day 03, line 1, column 1"));
+        assert!(errors[1].starts_with("2022 day 05, line 4, column 6"));
+        assert!(errors[2].starts_with("2022 day 10, line 2, column 1"));
+        assert!(errors[3].starts_with("2022 day 11, line 5, column 30"));
+    }
+
+    #[test]
+    fn test_summary() {
+        let days: Vec<(&dyn Puzzle, Result<String, String>)> = vec![
+            (&aoc_2022_day_01::Day01, Ok("1000\n".to_string())),
+            (&aoc_2022_day_02::Day02, Err("no input".to_string())),
+        ];
+        let runs = run_all(&days, Some(1), 2);
+        let summary = Summary(&runs).to_string();
+        let lines = summary.lines().collect::<Vec<_>>();
+
+        assert!(lines[0].starts_with("Day  Part 1  Part 2"));
+        assert!(lines[1].starts_with("01   1000    -"));
+        assert_eq!(lines[2], "02   error: no input");
+        assert!(lines[4].starts_with("2 day(s), 1 failed"));
+
+        let json = serde_json::to_value(&runs).unwrap();
+
+        assert_eq!(json[0]["parts"][0]["answer"], "1000");
+        assert!(json[0]["parse_ns"].is_u64());
+        assert!(json[1]["parse_ns"].is_null());
+    }
+}
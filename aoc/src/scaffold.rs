@@ -0,0 +1,356 @@
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::fixtures;
+
+const TEMPLATE_LIB: &str = include_str!("../../day-template/src/lib.rs");
+const TEMPLATE_GITIGNORE: &str = include_str!("../../day-template/.gitignore");
+const TEMPLATE_FIXTURES: [(&str, &str); 3] = [
+    (
+        "example.txt",
+        include_str!("../../day-template/fixtures/example.txt"),
+    ),
+    (
+        "example.part1",
+        include_str!("../../day-template/fixtures/example.part1"),
+    ),
+    (
+        "example.part2",
+        include_str!("../../day-template/fixtures/example.part2"),
+    ),
+];
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    NotAWorkspace(PathBuf),
+    AlreadyExists(PathBuf),
+    /// The file has no line starting with the prefix to register the day next to.
+    NoEntries(PathBuf, &'static str),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAWorkspace(root) => write!(
+                f,
+                "{} is not the root of the workspace, run `aoc new` from there",
+                root.display()
+            ),
+            Self::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            Self::NoEntries(path, prefix) => write!(
+                f,
+                "{} has no line starting with `{}` to register the day next to",
+                path.display(),
+                prefix.trim_start()
+            ),
+            Self::Io(path, source) => write!(f, "unable to write {}: {}", path.display(), source),
+        }
+    }
+}
+
+impl Error for ScaffoldError {}
+
+/// Creates `YYYY/day-NN` from the template and registers it in the workspace,
+/// the runner's dependencies and its registry.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<PathBuf, ScaffoldError> {
+    let path = format!("{}/day-{:02}", year, day);
+    let name = format!("aoc-{}-day-{:02}", year, day);
+    let crate_name = name.replace('-', "_");
+    let type_name = format!("Day{:02}", day);
+
+    let workspace_manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let registry = root.join("aoc").join("src").join("registry.rs");
+
+    if !workspace_manifest.is_file() || !registry.is_file() {
+        return Err(ScaffoldError::NotAWorkspace(root.to_path_buf()));
+    }
+
+    let dir = fixtures::day_dir(root, year, day);
+
+    if dir.exists() {
+        return Err(ScaffoldError::AlreadyExists(dir));
+    }
+
+    let instantiate = |template: &str| {
+        template
+            .replace("DayTemplate", &type_name)
+            .replace(
+                "const YEAR: u16 = 0;",
+                &format!("const YEAR: u16 = {};", year),
+            )
+            .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {};", day))
+    };
+
+    // the prefixes match the days of every year, all of them being in the 2000s,
+    // and every file is checked before anything is written
+    let registrations = [
+        register(
+            &workspace_manifest,
+            "    \"20",
+            &format!("    \"{}\",", path),
+        )?,
+        register(
+            &runner_manifest,
+            "aoc-20",
+            &format!("{} = {{ path = \"../{}\" }}", name, path),
+        )?,
+        register(
+            &registry,
+            "    &aoc_20",
+            &format!("    &{}::{},", crate_name, type_name),
+        )?,
+    ];
+
+    write(&dir.join("Cargo.toml"), &manifest(&name))?;
+    write(&dir.join("src").join("lib.rs"), &instantiate(TEMPLATE_LIB))?;
+    write(&dir.join(".gitignore"), TEMPLATE_GITIGNORE)?;
+
+    for (file, contents) in TEMPLATE_FIXTURES {
+        write(&dir.join("fixtures").join(file), contents)?;
+    }
+
+    for (path, contents) in &registrations {
+        write(path, contents)?;
+    }
+
+    Ok(dir)
+}
+
+/// The manifest of a day, which only builds as a member of the workspace.
+fn manifest(name: &str) -> String {
+    format!(
+        "[package]\nname = \"{}\"\nversion.workspace = true\nedition.workspace = true\n\n[dependencies]\naoc-core.workspace = true\n",
+        name
+    )
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    let to_error = |err| ScaffoldError::Io(path.to_path_buf(), err);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(to_error)?;
+    }
+
+    fs::write(path, contents).map_err(to_error)
+}
+
+/// The file at `path` with `line` inserted among the consecutive lines starting with
+/// `prefix`, keeping them sorted. The zero padded day numbers make the lexicographic
+/// order the calendar order.
+fn register(
+    path: &Path,
+    prefix: &'static str,
+    line: &str,
+) -> Result<(PathBuf, String), ScaffoldError> {
+    let contents =
+        fs::read_to_string(path).map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))?;
+    let mut lines = contents.lines().collect::<Vec<_>>();
+
+    // a day whose directory was deleted is still registered
+    if lines.contains(&line) {
+        return Ok((path.to_path_buf(), contents));
+    }
+
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with(prefix))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    // without any day to go next to, there is no telling where the list is
+    let Some(&last) = entries.last() else {
+        return Err(ScaffoldError::NoEntries(path.to_path_buf(), prefix));
+    };
+
+    let position = match entries.iter().find(|&&i| lines[i] > line) {
+        Some(&i) => i,
+        None => last + 1,
+    };
+
+    lines.insert(position, line);
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+
+    Ok((path.to_path_buf(), updated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+
+        fs::create_dir_all(root.path().join("aoc/src")).unwrap();
+        fs::write(
+            root.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2022/day-01\",\n    \"2022/day-03\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.path().join("aoc/Cargo.toml"),
+            "[dependencies]\nclap = \"4\"\naoc-2022-day-01 = { path = \"../2022/day-01\" }\naoc-2022-day-03 = { path = \"../2022/day-03\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.path().join("aoc/src/registry.rs"),
+            "pub static DAYS: &[&dyn Puzzle] = &[\n    &aoc_2022_day_01::Day01,\n    &aoc_2022_day_03::Day03,\n];\n",
+        )
+        .unwrap();
+
+        root
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = workspace();
+        let dir = new_day(root.path(), 2022, 2).unwrap();
+
+        let manifest = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+
+        assert!(dir.ends_with("2022/day-02"));
+        assert_eq!(
+            manifest,
+            "[package]\nname = \"aoc-2022-day-02\"\nversion.workspace = true\nedition.workspace = true\n\n[dependencies]\naoc-core.workspace = true\n"
+        );
+        assert!(lib.contains("pub struct Day02;"));
+        assert!(lib.contains("const YEAR: u16 = 2022;"));
+        assert!(lib.contains("const DAY: u8 = 2;"));
+
+        // the example is not solved until its answers are filled in
+        let examples = fixtures::discover(&dir.join("fixtures")).unwrap();
+        assert_eq!(examples.len(), 1);
+        assert!(examples[0]
+            .expected
+            .iter()
+            .all(|(_, answer)| answer == fixtures::PLACEHOLDER));
+
+        assert_eq!(
+            fs::read_to_string(root.path().join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2022/day-01\",\n    \"2022/day-02\",\n    \"2022/day-03\",\n]\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.path().join("aoc/Cargo.toml")).unwrap(),
+            "[dependencies]\nclap = \"4\"\naoc-2022-day-01 = { path = \"../2022/day-01\" }\naoc-2022-day-02 = { path = \"../2022/day-02\" }\naoc-2022-day-03 = { path = \"../2022/day-03\" }\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.path().join("aoc/src/registry.rs")).unwrap(),
+            "pub static DAYS: &[&dyn Puzzle] = &[\n    &aoc_2022_day_01::Day01,\n    &aoc_2022_day_02::Day02,\n    &aoc_2022_day_03::Day03,\n];\n"
+        );
+    }
+
+    #[test]
+    fn test_new_day_appends_last() {
+        let root = workspace();
+        new_day(root.path(), 2022, 14).unwrap();
+
+        assert!(fs::read_to_string(root.path().join("aoc/src/registry.rs"))
+            .unwrap()
+            .contains("    &aoc_2022_day_03::Day03,\n    &aoc_2022_day_14::Day14,\n];"));
+    }
+
+    #[test]
+    fn test_new_year() {
+        let root = workspace();
+        let dir = new_day(root.path(), 2015, 1).unwrap();
+
+        assert!(dir.ends_with("2015/day-01"));
+        assert!(fs::read_to_string(root.path().join("Cargo.toml"))
+            .unwrap()
+            .contains("    \"aoc\",\n    \"2015/day-01\",\n    \"2022/day-01\","));
+        assert!(fs::read_to_string(root.path().join("aoc/src/registry.rs"))
+            .unwrap()
+            .contains("&[\n    &aoc_2015_day_01::Day01,\n    &aoc_2022_day_01::Day01,"));
+    }
+
+    #[test]
+    fn test_new_day_needs_a_registered_day() {
+        let root = workspace();
+        fs::write(
+            root.path().join("aoc/src/registry.rs"),
+            "pub static DAYS: &[&dyn Puzzle] = &[];\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            new_day(root.path(), 2022, 2).unwrap_err().to_string(),
+            format!(
+                "{} has no line starting with `&aoc_20` to register the day next to",
+                root.path().join("aoc/src/registry.rs").display()
+            )
+        );
+        assert!(!root.path().join("2022/day-02").exists());
+        assert!(!fs::read_to_string(root.path().join("Cargo.toml"))
+            .unwrap()
+            .contains("2022/day-02"));
+    }
+
+    #[test]
+    fn test_registers_once() {
+        let root = workspace();
+        let before = ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/registry.rs"]
+            .map(|file| fs::read_to_string(root.path().join(file)).unwrap());
+
+        // day 3 is registered but its directory is gone
+        new_day(root.path(), 2022, 3).unwrap();
+
+        let after = ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/registry.rs"]
+            .map(|file| fs::read_to_string(root.path().join(file)).unwrap());
+
+        assert_eq!(before, after);
+        assert!(root.path().join("2022/day-03/src/lib.rs").is_file());
+    }
+
+    #[test]
+    fn test_new_day_builds() {
+        let root = workspace();
+        let core = Path::new(env!("CARGO_MANIFEST_DIR")).join("../aoc-core");
+
+        // only the days are members, the runner of `workspace` is not a real crate
+        fs::write(
+            root.path().join("Cargo.toml"),
+            format!(
+                "[workspace]\nresolver = \"2\"\nmembers = [\n    \"2022/day-01\",\n]\n\n[workspace.package]\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[workspace.dependencies]\naoc-core = {{ path = {:?} }}\n",
+                core.canonicalize().unwrap()
+            ),
+        )
+        .unwrap();
+        write(
+            &root.path().join("2022/day-01/Cargo.toml"),
+            &manifest("aoc-2022-day-01"),
+        )
+        .unwrap();
+        write(&root.path().join("2022/day-01/src/lib.rs"), "").unwrap();
+
+        new_day(root.path(), 2022, 2).unwrap();
+
+        let status = std::process::Command::new(env!("CARGO"))
+            .args(["build", "--offline", "--quiet", "-p", "aoc-2022-day-02"])
+            .arg("--manifest-path")
+            .arg(root.path().join("Cargo.toml"))
+            .env("CARGO_TARGET_DIR", root.path().join("target"))
+            .status()
+            .unwrap();
+
+        assert!(status.success());
+    }
+
+    #[test]
+    fn test_refuses_to_overwrite() {
+        let root = workspace();
+        fs::create_dir_all(root.path().join("2022/day-03")).unwrap();
+
+        assert!(matches!(
+            new_day(root.path(), 2022, 3),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+    }
+}
@@ -0,0 +1,395 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_core::input;
+use serde::{Deserialize, Serialize};
+
+use crate::{client::Client, Error};
+
+pub fn default_path(year: u16) -> PathBuf {
+    input::year_dir(year).join("submissions.json")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint of which way.
+    Wrong,
+    /// Sent before the end of the cooldown, so not checked at all.
+    TooSoon,
+    AlreadySolved,
+    Unknown,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Self::Correct => "the right answer",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::Wrong => "not the right answer",
+            Self::TooSoon => "sent too soon after the previous answer",
+            Self::AlreadySolved => "for a part that was already solved",
+            Self::Unknown => "answered with an unknown page",
+        };
+
+        f.write_str(text)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub outcome: Outcome,
+    pub wait: Option<Duration>,
+}
+
+impl Response {
+    pub fn parse(page: &str) -> Self {
+        let outcome = if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if page.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Outcome::TooSoon
+        } else if page.contains("Did you already complete it?") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown
+        };
+
+        Self {
+            outcome,
+            wait: parse_wait(page),
+        }
+    }
+}
+
+/// The wait in `You have 4m 37s left to wait` or `Please wait one minute`.
+fn parse_wait(page: &str) -> Option<Duration> {
+    if let Some(end) = page.find(" left to wait") {
+        let start = page[..end].rfind("You have ")? + "You have ".len();
+        let mut seconds = 0;
+
+        for amount in page[start..end].split_whitespace() {
+            let (number, unit) = amount.split_at(amount.len() - 1);
+            let number = number.parse::<u64>().ok()?;
+
+            seconds += match unit {
+                "h" => number * 3600,
+                "m" => number * 60,
+                "s" => number,
+                _ => return None,
+            };
+        }
+
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let (_, rest) = page.split_once("lease wait ")?;
+    let mut words = rest.split_whitespace();
+    let count = match words.next()? {
+        "one" | "a" => 1,
+        number => number.parse().ok()?,
+    };
+
+    match words.next()?.trim_end_matches(['s', '.']) {
+        "minute" => Some(Duration::from_secs(count * 60)),
+        "second" => Some(Duration::from_secs(count)),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// In seconds since the Unix epoch.
+    pub sent_at: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    AlreadySent(Outcome),
+    /// Not above an answer that was too low.
+    TooLow(String),
+    /// Not below an answer that was too high.
+    TooHigh(String),
+    Cooldown(Duration),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadySolved(answer) => write!(f, "already solved with {}", answer),
+            Self::AlreadySent(outcome) => write!(f, "already sent, it was {}", outcome),
+            Self::TooLow(bound) => write!(f, "too low, {} already was", bound),
+            Self::TooHigh(bound) => write!(f, "too high, {} already was", bound),
+            Self::Cooldown(wait) => {
+                write!(f, "wait {}s before sending another answer", wait.as_secs())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let data = match fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(Error::Io(path.to_path_buf(), err)),
+        };
+
+        let attempts =
+            serde_json::from_str(&data).map_err(|err| Error::Invalid(path.to_path_buf(), err))?;
+
+        Ok(Self { attempts })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let to_error = |err| Error::Io(path.to_path_buf(), err);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(to_error)?;
+        }
+
+        let mut data = serde_json::to_string_pretty(&self.attempts).unwrap();
+        data.push('\n');
+
+        fs::write(path, data).map_err(to_error)
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// Refuses an answer the website would turn down anyway.
+    pub fn check(&self, day: u8, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|a| a.day == day && a.part == part)
+            .collect::<Vec<_>>();
+
+        if let Some(solved) = attempts.iter().find(|a| a.outcome == Outcome::Correct) {
+            return Err(Refusal::AlreadySolved(solved.answer.clone()));
+        }
+
+        // only a verdict rules an answer out, one sent too soon, to a solved part
+        // or answered with a page we cannot read was never judged
+        let sent = attempts.iter().find(|a| {
+            a.answer == answer
+                && !matches!(
+                    a.outcome,
+                    Outcome::TooSoon | Outcome::Unknown | Outcome::AlreadySolved
+                )
+        });
+
+        if let Some(sent) = sent {
+            return Err(Refusal::AlreadySent(sent.outcome));
+        }
+
+        if let Ok(number) = answer.parse::<i128>() {
+            for attempt in &attempts {
+                let Ok(bound) = attempt.answer.parse::<i128>() else {
+                    continue;
+                };
+
+                match attempt.outcome {
+                    Outcome::TooLow if number <= bound => {
+                        return Err(Refusal::TooLow(attempt.answer.clone()))
+                    }
+                    Outcome::TooHigh if number >= bound => {
+                        return Err(Refusal::TooHigh(attempt.answer.clone()))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let wait_until = attempts.iter().filter_map(|a| a.wait_until).max();
+
+        match wait_until {
+            Some(wait_until) if wait_until > now => {
+                Err(Refusal::Cooldown(Duration::from_secs(wait_until - now)))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> Result<Response, Error> {
+    history
+        .check(day, part, answer, now)
+        .map_err(Error::Refused)?;
+
+    let response = Response::parse(&client.submit(year, day, part, answer)?);
+
+    history.record(Attempt {
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome: response.outcome,
+        sent_at: now,
+        wait_until: response.wait.map(|wait| now + wait.as_secs()),
+    });
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(answer: &str, outcome: Outcome, wait_until: Option<u64>) -> Attempt {
+        Attempt {
+            day: 7,
+            part: 1,
+            answer: answer.to_string(),
+            outcome,
+            sent_at: 1000,
+            wait_until,
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let response = |page| Response::parse(page);
+
+        assert_eq!(
+            response(
+                "<article><p>That's the right answer!  You are one gold star closer.</p></article>"
+            ),
+            Response {
+                outcome: Outcome::Correct,
+                wait: None
+            }
+        );
+        assert_eq!(
+            response("<p>That's not the right answer; your answer is too high.  Please wait one minute before trying again.</p>"),
+            Response {
+                outcome: Outcome::TooHigh,
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            response(
+                "<p>That's not the right answer.  please wait 5 minutes before trying again.</p>"
+            )
+            .wait,
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            response("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 37s left to wait.</p>"),
+            Response {
+                outcome: Outcome::TooSoon,
+                wait: Some(Duration::from_secs(277))
+            }
+        );
+        assert_eq!(
+            response("<p>You don't seem to be solving the right level.  Did you already complete it?</p>")
+                .outcome,
+            Outcome::AlreadySolved
+        );
+        assert_eq!(response("<html></html>").outcome, Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut history = History::default();
+
+        assert_eq!(history.check(7, 1, "100", 1000), Ok(()));
+
+        history.record(attempt("100", Outcome::TooLow, Some(1060)));
+        history.record(attempt("500", Outcome::TooHigh, Some(1060)));
+
+        assert_eq!(
+            history.check(7, 1, "300", 1030),
+            Err(Refusal::Cooldown(Duration::from_secs(30)))
+        );
+        assert_eq!(history.check(7, 1, "300", 1060), Ok(()));
+        assert_eq!(
+            history.check(7, 1, "100", 2000),
+            Err(Refusal::AlreadySent(Outcome::TooLow))
+        );
+        assert_eq!(
+            history.check(7, 1, "50", 2000),
+            Err(Refusal::TooLow("100".to_string()))
+        );
+        assert_eq!(
+            history.check(7, 1, "600", 2000),
+            Err(Refusal::TooHigh("500".to_string()))
+        );
+        assert_eq!(history.check(7, 2, "600", 1030), Ok(()));
+
+        history.record(attempt("300", Outcome::Correct, None));
+
+        assert_eq!(
+            history.check(7, 1, "301", 2000),
+            Err(Refusal::AlreadySolved("300".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_answer_sent_too_soon_can_be_sent_again() {
+        let mut history = History::default();
+        history.record(attempt("abc", Outcome::TooSoon, Some(1010)));
+
+        assert_eq!(history.check(7, 1, "abc", 1010), Ok(()));
+    }
+
+    #[test]
+    fn test_answer_never_judged_can_be_sent_again() {
+        let mut history = History::default();
+        history.record(attempt("abc", Outcome::Unknown, None));
+        history.record(attempt("def", Outcome::AlreadySolved, None));
+
+        assert_eq!(history.check(7, 1, "abc", 1010), Ok(()));
+        assert_eq!(history.check(7, 1, "def", 1010), Ok(()));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("inputs").join("submissions.json");
+        let mut history = History::load(&path).unwrap();
+        history.record(attempt("100", Outcome::TooLow, Some(1060)));
+        history.save(&path).unwrap();
+
+        assert_eq!(History::load(&path).unwrap(), history);
+        assert!(fs::read_to_string(&path).unwrap().contains("\"too_low\""));
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use aoc_core::visual::Frame;
+
+/// Clears the terminal and moves the cursor to its top left corner.
+const CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// Plays the frames of a visualization in the terminal or writes them all to a file.
+pub struct Player {
+    out: Box<dyn Write>,
+    /// The pause after every frame, `None` when writing to a file.
+    delay: Option<Duration>,
+    frames: usize,
+    error: Option<io::Error>,
+}
+
+impl Player {
+    pub fn terminal(delay: Duration) -> Self {
+        Self::new(Box::new(io::stdout()), Some(delay))
+    }
+
+    pub fn file(path: &Path) -> io::Result<Self> {
+        let file = File::create(path)?;
+
+        Ok(Self::writer(BufWriter::new(file)))
+    }
+
+    /// Writes every frame after the previous one, with a header, without pausing.
+    pub fn writer(out: impl Write + 'static) -> Self {
+        Self::new(Box::new(out), None)
+    }
+
+    fn new(out: Box<dyn Write>, delay: Option<Duration>) -> Self {
+        Self {
+            out,
+            delay,
+            frames: 0,
+            error: None,
+        }
+    }
+
+    /// Shows a frame. Once writing failed the following frames are dropped
+    /// and the error is reported by [`Player::finish`].
+    pub fn show(&mut self, frame: Frame) {
+        if self.error.is_some() {
+            return;
+        }
+
+        self.frames += 1;
+
+        if let Err(err) = self.write(&frame) {
+            self.error = Some(err);
+        }
+    }
+
+    fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        match self.delay {
+            Some(delay) => {
+                writeln!(self.out, "{}{}", CLEAR, frame)?;
+                self.out.flush()?;
+                thread::sleep(delay);
+            }
+            None => write!(self.out, "--- frame {} ---\n{}\n\n", self.frames, frame)?,
+        }
+
+        Ok(())
+    }
+
+    /// The number of frames shown, or the first error writing them.
+    pub fn finish(mut self) -> io::Result<usize> {
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => self.out.flush().map(|_| self.frames),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{visual::Canvas, Puzzle};
+
+    #[test]
+    fn test_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("frames.txt");
+        let mut player = Player::file(&path).unwrap();
+
+        for caption in ["first", "second"] {
+            player.show(Frame::new(caption, Canvas::new(1, 1)));
+        }
+
+        assert_eq!(player.finish().unwrap(), 2);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "--- frame 1 ---\nfirst\n\n\n\n--- frame 2 ---\nsecond\n\n\n\n"
+        );
+    }
+
+    #[test]
+    fn test_day_frames() {
+        let data = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 1 from 3 to 1\n";
+        let puzzle: &dyn Puzzle = &aoc_2022_day_05::Day05;
+        let input = puzzle.parse(data).unwrap();
+        let mut frames = vec![];
+
+        assert!(puzzle.visualize(&input, 1, &mut |frame| frames.push(frame)));
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[2].caption, "move 3 from 1 to 3");
+        assert_eq!(
+            frames[2].canvas.to_string(),
+            "        [Z]\n        [N]\n    [C] [D]\n    [M] [P]\n 1   2   3"
+        );
+
+        let input = aoc_2022_day_01::Day01.parse("1000").unwrap();
+        assert!(!aoc_2022_day_01::Day01.visualize(&input, 1, &mut |_| {}));
+    }
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use std::fs;
+
+use aoc::{
+    client::Client,
+    config::Config,
+    fetch::{fetch, Fetched},
+    Error,
+};
+use common::Stub;
+
+fn config(stub: &Stub) -> Config {
+    Config {
+        session: Some("53616c74".to_string()),
+        base_url: Some(stub.base_url.clone()),
+    }
+}
+
+#[test]
+fn test_downloads_once() {
+    let stub = Stub::start(|_| (200, "1000\n2000\n".to_string()));
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("inputs").join("2021").join("day-14.txt");
+
+    assert_eq!(
+        fetch(&config(&stub), 2021, 14, &path).unwrap(),
+        Fetched::Downloaded
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+    assert_eq!(
+        fetch(&config(&stub), 2021, 14, &path).unwrap(),
+        Fetched::Cached
+    );
+
+    let requests = stub.requests();
+
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2021/day/14/input");
+    assert_eq!(requests[0].header("cookie"), Some("session=53616c74"));
+    assert!(requests[0]
+        .header("user-agent")
+        .is_some_and(|agent| agent.contains("advent-of-code-22")));
+}
+
+#[test]
+fn test_cached_input_needs_no_session() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("day-01.txt");
+    fs::write(&path, "1000\n").unwrap();
+
+    assert_eq!(
+        fetch(&Config::default(), 2022, 1, &path).unwrap(),
+        Fetched::Cached
+    );
+}
+
+#[test]
+fn test_missing_session() {
+    let stub = Stub::start(|_| (200, String::new()));
+    let dir = tempfile::tempdir().unwrap();
+    let config = Config {
+        session: None,
+        ..config(&stub)
+    };
+
+    let err = fetch(&config, 2022, 1, &dir.path().join("day-01.txt")).unwrap_err();
+
+    assert!(matches!(err, Error::MissingSession));
+    assert!(stub.requests().is_empty());
+}
+
+#[test]
+fn test_errors_are_not_cached() {
+    let stub = Stub::start(|request| match request.path.as_str() {
+        "/2022/day/25/input" => (
+            404,
+            "Please don't repeatedly request this endpoint".to_string(),
+        ),
+        _ => (
+            400,
+            "Puzzle inputs differ by user.  Please log in.".to_string(),
+        ),
+    });
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("day-25.txt");
+
+    let err = fetch(&config(&stub), 2022, 25, &path).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .ends_with("answered 404, is the puzzle unlocked yet?"));
+    assert!(!path.exists());
+
+    let err = fetch(&config(&stub), 2022, 3, &dir.path().join("day-03.txt")).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("is the session token still valid?"));
+}
+
+#[test]
+fn test_puzzle_page() {
+    let stub = Stub::start(|_| (200, "<article></article>".to_string()));
+    let client = Client::new(&config(&stub)).unwrap();
+
+    assert_eq!(client.puzzle(2022, 7).unwrap(), "<article></article>");
+    assert_eq!(stub.requests()[0].path, "/2022/day/7");
+}
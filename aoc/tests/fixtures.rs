@@ -0,0 +1,90 @@
+//! Runs every day against the example fixtures in `YYYY/day-NN/fixtures`,
+//! generating one test per fixture, part and way of saving the input.
+//! Every implementation of a part is checked against the expected answer.
+//! The placeholder answers of a new day are ignored and listed as `[unfilled]`.
+
+use std::{fs, path::Path};
+
+use aoc::{fixtures, registry};
+use aoc_core::input::{self, Normalize};
+use libtest_mimic::{Arguments, Trial};
+
+type Mangle = fn(&str) -> String;
+
+/// The ways an input gets mangled on its way from the website to a teammate's disk.
+/// The normalization must make every variant solve like the original.
+const VARIANTS: [(&str, Mangle); 5] = [
+    ("original", str::to_string),
+    ("crlf", |data| data.replace('\n', "\r\n")),
+    ("trailing_newlines", |data| format!("{}\n\n\n", data)),
+    ("trailing_spaces", |data| data.replace('\n', "  \n")),
+    ("leading_newlines", |data| format!("\n\n{}", data)),
+];
+
+fn main() {
+    let args = Arguments::from_args();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let mut trials = vec![];
+
+    for &puzzle in registry::DAYS {
+        let dir = fixtures::fixtures_dir(root, puzzle.year(), puzzle.day());
+
+        for fixture in fixtures::discover(&dir).expect("Unable to read the fixtures") {
+            for (part, expected) in fixture.expected {
+                for (variant, mangle) in VARIANTS {
+                    // stray spaces and lines are only cleaned up for days that opted into it
+                    if matches!(variant, "trailing_spaces" | "leading_newlines")
+                        && puzzle.normalize() != Normalize::Full
+                    {
+                        continue;
+                    }
+
+                    let name = format!(
+                        "{}::day_{:02}::{}::part_{}::{}",
+                        puzzle.year(),
+                        puzzle.day(),
+                        fixture.name,
+                        part,
+                        variant
+                    );
+                    let input = fixture.input.clone();
+                    let expected = expected.clone();
+                    let unfilled = expected == fixtures::PLACEHOLDER;
+
+                    let trial = Trial::test(name, move || {
+                        if unfilled {
+                            return Err("the expected answer is not filled in yet".into());
+                        }
+
+                        let data = mangle(&fs::read_to_string(&input)?);
+                        let data = input::normalize(&data, puzzle.normalize());
+                        let input = puzzle.parse(&data)?;
+
+                        // the alternative implementations have to solve it too
+                        for name in puzzle.implementations(part) {
+                            let answer = puzzle.solve_with(&input, part, name).unwrap();
+
+                            if answer != expected {
+                                return Err(format!(
+                                    "expected\n{}\nbut `{}` got\n{}",
+                                    expected, name, answer
+                                )
+                                .into());
+                            }
+                        }
+
+                        Ok(())
+                    });
+
+                    // a freshly created day is reported until its example is filled in
+                    trials.push(match unfilled {
+                        true => trial.with_ignored_flag(true).with_kind("unfilled"),
+                        false => trial,
+                    });
+                }
+            }
+        }
+    }
+
+    libtest_mimic::run(&args, trials).exit();
+}
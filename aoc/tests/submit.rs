@@ -0,0 +1,92 @@
+mod common;
+
+use std::time::Duration;
+
+use aoc::{
+    client::Client,
+    config::Config,
+    submit::{submit, History, Outcome, Refusal},
+    Error,
+};
+use common::Stub;
+
+const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again.</p></article>";
+const RIGHT: &str = "<article><p>That's the right answer!  You are one gold star closer to collecting enough star fruit.</p></article>";
+
+fn client(stub: &Stub) -> Client {
+    Client::new(&Config {
+        session: Some("53616c74".to_string()),
+        base_url: Some(stub.base_url.clone()),
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_submit() {
+    let stub = Stub::start(|request| match request.body.as_str() {
+        "level=1&answer=95437" => (200, RIGHT.to_string()),
+        _ => (200, TOO_LOW.to_string()),
+    });
+    let client = client(&stub);
+    let mut history = History::default();
+
+    let response = submit(&client, &mut history, 2022, 7, 1, "90000", 1000).unwrap();
+
+    assert_eq!(response.outcome, Outcome::TooLow);
+    assert_eq!(response.wait, Some(Duration::from_secs(60)));
+
+    let requests = stub.requests();
+
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2022/day/7/answer");
+    assert_eq!(requests[0].body, "level=1&answer=90000");
+    assert_eq!(requests[0].header("cookie"), Some("session=53616c74"));
+
+    let response = submit(&client, &mut history, 2022, 7, 1, "95437", 1060).unwrap();
+
+    assert_eq!(response.outcome, Outcome::Correct);
+    assert_eq!(history.attempts().len(), 2);
+    assert_eq!(history.attempts()[1].wait_until, None);
+}
+
+#[test]
+fn test_refused_answers_are_not_sent() {
+    let stub = Stub::start(|_| (200, TOO_LOW.to_string()));
+    let client = client(&stub);
+    let mut history = History::default();
+
+    submit(&client, &mut history, 2022, 7, 1, "90000", 1000).unwrap();
+
+    let refusal = |result| match result {
+        Err(Error::Refused(refusal)) => refusal,
+        other => panic!("expected a refusal, got {:?}", other),
+    };
+
+    assert_eq!(
+        refusal(submit(&client, &mut history, 2022, 7, 1, "95437", 1030)),
+        Refusal::Cooldown(Duration::from_secs(30))
+    );
+    assert_eq!(
+        refusal(submit(&client, &mut history, 2022, 7, 1, "80000", 2000)),
+        Refusal::TooLow("90000".to_string())
+    );
+    assert_eq!(
+        refusal(submit(&client, &mut history, 2022, 7, 1, "90000", 2000)),
+        Refusal::AlreadySent(Outcome::TooLow)
+    );
+    assert_eq!(stub.requests().len(), 1);
+    assert_eq!(history.attempts().len(), 1);
+}
+
+#[test]
+fn test_server_errors() {
+    let stub = Stub::start(|_| (500, "Internal Server Error".to_string()));
+    let mut history = History::default();
+
+    let err = submit(&client(&stub), &mut history, 2022, 7, 1, "1", 1000).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("is the session token still valid?"));
+    assert!(history.attempts().is_empty());
+}
@@ -0,0 +1,24 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct DayTemplate;
+
+impl Solution for DayTemplate {
+    const YEAR: u16 = 0;
+    const DAY: u8 = 0;
+
+    type Input = String;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Ok(data.to_string())
+    }
+
+    fn part_1(_input: &Self::Input) -> Self::Part1 {
+        String::new()
+    }
+
+    fn part_2(_input: &Self::Input) -> Self::Part2 {
+        String::new()
+    }
+}